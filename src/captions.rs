@@ -0,0 +1,301 @@
+//! Subtitle export (SRT and WebVTT) from word timestamps.
+//!
+//! Captions are built from [`SynthesisResult::words`], so their timing follows
+//! the synthesized audio rather than an estimate from text length.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::segment::{base_lang, is_abbreviation_period};
+use crate::{SynthesisResult, WordTimestamp};
+
+/// How words are grouped into caption cues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptionGrouping {
+    /// One cue per sentence.
+    Sentence,
+    /// Cues of at most this many characters. Sentence ends still start a new
+    /// cue; a single word longer than the limit gets a cue of its own.
+    MaxLineLength(usize),
+}
+
+/// A single timed caption.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Caption {
+    /// Caption text (the grouped words joined by spaces, or directly in
+    /// Chinese and Japanese)
+    pub text: String,
+    /// Start time in seconds
+    pub start_secs: f64,
+    /// End time in seconds
+    pub end_secs: f64,
+}
+
+impl SynthesisResult {
+    /// Group the word timestamps into captions.
+    ///
+    /// Sentences end at `.`, `!`, `?`, `…` and their CJK forms, but not at
+    /// the periods of abbreviations and initials (`"Dr."`, `"U.S."`) in the
+    /// result's [`language`](SynthesisResult::language). Returns an empty
+    /// list if the engine did not provide word timestamps.
+    pub fn captions(&self, grouping: CaptionGrouping) -> Vec<Caption> {
+        let lang = self.language.as_deref().unwrap_or("en-us");
+        group_words(&self.words, grouping, lang)
+    }
+
+    /// Render captions in SubRip (`.srt`) format.
+    pub fn to_srt(&self, grouping: CaptionGrouping) -> String {
+        let mut out = String::new();
+        for (i, caption) in self.captions(grouping).iter().enumerate() {
+            let _ = write!(
+                out,
+                "{}\n{} --> {}\n{}\n\n",
+                i + 1,
+                format_timestamp(caption.start_secs, ','),
+                format_timestamp(caption.end_secs, ','),
+                caption.text
+            );
+        }
+        out
+    }
+
+    /// Render captions in WebVTT (`.vtt`) format.
+    pub fn to_webvtt(&self, grouping: CaptionGrouping) -> String {
+        let mut out = String::from("WEBVTT\n\n");
+        for caption in self.captions(grouping) {
+            let _ = write!(
+                out,
+                "{} --> {}\n{}\n\n",
+                format_timestamp(caption.start_secs, '.'),
+                format_timestamp(caption.end_secs, '.'),
+                caption.text
+            );
+        }
+        out
+    }
+
+    /// Write captions to a SubRip (`.srt`) file.
+    pub fn write_srt(
+        &self,
+        path: &Path,
+        grouping: CaptionGrouping,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::write(path, self.to_srt(grouping))?;
+        Ok(())
+    }
+
+    /// Write captions to a WebVTT (`.vtt`) file.
+    pub fn write_webvtt(
+        &self,
+        path: &Path,
+        grouping: CaptionGrouping,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::write(path, self.to_webvtt(grouping))?;
+        Ok(())
+    }
+}
+
+fn group_words(words: &[WordTimestamp], grouping: CaptionGrouping, lang: &str) -> Vec<Caption> {
+    let mut captions = Vec::new();
+    let mut current: Option<Caption> = None;
+
+    let mut words = words
+        .iter()
+        .map(|word| (word.text.trim(), word))
+        .filter(|(text, _)| !text.is_empty())
+        .peekable();
+    while let Some((text, word)) = words.next() {
+        if let (Some(caption), CaptionGrouping::MaxLineLength(max)) = (&current, grouping) {
+            let separator = word_separator(&caption.text, text, lang);
+            let len = caption.text.chars().count() + separator.len() + text.chars().count();
+            if len > max {
+                captions.extend(current.take());
+            }
+        }
+
+        match current.as_mut() {
+            Some(caption) => {
+                caption
+                    .text
+                    .push_str(word_separator(&caption.text, text, lang));
+                caption.text.push_str(text);
+                caption.end_secs = word.end_secs;
+            }
+            None => {
+                current = Some(Caption {
+                    text: text.to_string(),
+                    start_secs: word.start_secs,
+                    end_secs: word.end_secs,
+                });
+            }
+        }
+
+        let next = words.peek().map(|&(next, _)| next);
+        if ends_sentence(text, next, lang) {
+            captions.extend(current.take());
+        }
+    }
+
+    captions.extend(current);
+    captions
+}
+
+/// What goes between `prev` and the following word `next` of a caption.
+///
+/// Chinese and Japanese are written without spaces, except between two
+/// stretches of ASCII such as Latin words.
+fn word_separator(prev: &str, next: &str, lang: &str) -> &'static str {
+    if !matches!(base_lang(lang), "cmn" | "ja" | "yue" | "zh") {
+        return " ";
+    }
+    let ascii = |c: Option<char>| c.is_some_and(|c| c.is_ascii_graphic());
+    if ascii(prev.chars().next_back()) && ascii(next.chars().next()) {
+        " "
+    } else {
+        ""
+    }
+}
+
+/// Whether `word` ends a sentence when followed by the word `next`.
+fn ends_sentence(word: &str, next: Option<&str>, lang: &str) -> bool {
+    let word = word.trim_end_matches(['"', ')', '\u{201d}', '」', '』', '）']);
+    if word.ends_with(['!', '?', '…', '。', '．', '｡', '！', '？']) {
+        return true;
+    }
+    if !word.ends_with('.') {
+        return false;
+    }
+    let Some(next) = next else {
+        return true;
+    };
+    // Enough of the text around the period for the abbreviation rules.
+    let text = format!("{word} {next}");
+    !is_abbreviation_period(&text, word.len() - 1, lang)
+}
+
+/// Format seconds as `HH:MM:SS<sep>mmm`.
+fn format_timestamp(secs: f64, millis_separator: char) -> String {
+    let total_ms = (secs.max(0.0) * 1000.0).round() as u64;
+    let (hours, rem) = (total_ms / 3_600_000, total_ms % 3_600_000);
+    let (minutes, rem) = (rem / 60_000, rem % 60_000);
+    let (seconds, millis) = (rem / 1000, rem % 1000);
+    format!("{hours:02}:{minutes:02}:{seconds:02}{millis_separator}{millis:03}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CaptionGrouping;
+    use crate::{SynthesisResult, WordTimestamp};
+
+    fn result(words: &[(&str, f64, f64)]) -> SynthesisResult {
+        let mut offset = 0;
+        let words = words
+            .iter()
+            .map(|&(text, start_secs, end_secs)| {
+                let span = offset..offset + text.len();
+                offset = span.end + 1;
+                WordTimestamp {
+                    text: text.to_string(),
+                    text_span: span,
+                    phonemes: String::new(),
+                    start_secs,
+                    end_secs,
+                }
+            })
+            .collect();
+        SynthesisResult {
+            samples: Vec::new(),
+            sample_rate: 24000,
+            words,
+            phonemes: Vec::new(),
+            marks: Vec::new(),
+            language: None,
+        }
+    }
+
+    #[test]
+    fn srt_groups_by_sentence() {
+        let r = result(&[
+            ("Hello", 0.1, 0.4),
+            ("world.", 0.45, 0.9),
+            ("Bye!", 1.2, 3661.5),
+        ]);
+        assert_eq!(
+            r.to_srt(CaptionGrouping::Sentence),
+            "1\n00:00:00,100 --> 00:00:00,900\nHello world.\n\n\
+             2\n00:00:01,200 --> 01:01:01,500\nBye!\n\n"
+        );
+    }
+
+    #[test]
+    fn sentences_skip_abbreviations_and_split_cjk() {
+        let r = result(&[
+            ("Dr.", 0.0, 0.3),
+            ("Smith", 0.3, 0.6),
+            ("met", 0.6, 0.8),
+            ("U.S.", 0.8, 1.2),
+            ("troops,", 1.2, 1.6),
+            ("e.g.", 1.6, 2.0),
+            ("these.", 2.0, 2.4),
+            ("今日は晴れ。", 2.5, 3.5),
+            ("明日は？", 3.5, 4.0),
+        ]);
+        let cues: Vec<String> = r
+            .captions(CaptionGrouping::Sentence)
+            .into_iter()
+            .map(|caption| caption.text)
+            .collect();
+        assert_eq!(
+            cues,
+            [
+                "Dr. Smith met U.S. troops, e.g. these.",
+                "今日は晴れ。",
+                "明日は？"
+            ]
+        );
+    }
+
+    #[test]
+    fn joins_chinese_and_japanese_words_without_spaces() {
+        let mut r = result(&[
+            ("今日は", 0.0, 0.4),
+            ("Kokoro", 0.4, 0.8),
+            ("TTS", 0.8, 1.0),
+            ("で", 1.0, 1.1),
+            ("話す", 1.1, 1.4),
+            ("。", 1.4, 1.5),
+        ]);
+        r.language = Some("ja".to_string());
+        let cues = |grouping| -> Vec<String> {
+            r.captions(grouping)
+                .into_iter()
+                .map(|caption| caption.text)
+                .collect()
+        };
+        assert_eq!(
+            cues(CaptionGrouping::Sentence),
+            ["今日はKokoro TTSで話す。"]
+        );
+        assert_eq!(
+            cues(CaptionGrouping::MaxLineLength(10)),
+            ["今日はKokoro", "TTSで話す。"]
+        );
+    }
+
+    #[test]
+    fn webvtt_respects_max_line_length() {
+        let r = result(&[
+            ("one", 0.0, 0.2),
+            ("two", 0.2, 0.4),
+            ("three", 0.4, 0.7),
+            ("four.", 0.7, 1.0),
+        ]);
+        assert_eq!(
+            r.to_webvtt(CaptionGrouping::MaxLineLength(9)),
+            "WEBVTT\n\n\
+             00:00:00.000 --> 00:00:00.400\none two\n\n\
+             00:00:00.400 --> 00:00:00.700\nthree\n\n\
+             00:00:00.700 --> 00:00:01.000\nfour.\n\n"
+        );
+    }
+}
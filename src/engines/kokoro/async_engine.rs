@@ -0,0 +1,191 @@
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::sync::mpsc;
+
+use super::engine::{KokoroEngine, KokoroInferenceParams, KokoroModelParams};
+use super::model::KokoroError;
+use super::voices::VoiceInfo;
+use crate::{SynthesisChunk, SynthesisEngine, SynthesisResult};
+
+/// Number of synthesized chunks buffered ahead of a slow stream consumer.
+const STREAM_BUFFER_CHUNKS: usize = 2;
+
+/// Async handle to a [`KokoroEngine`] for use from Tokio applications.
+///
+/// Phonemization (espeak-ng) and ONNX inference are blocking, so every call is
+/// offloaded to Tokio's blocking thread pool with `spawn_blocking`. The handle
+/// is cheap to clone; clones share one engine and requests are serialized.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tts_rs::engines::kokoro::{AsyncKokoroEngine, KokoroEngine, KokoroModelParams};
+/// use std::path::PathBuf;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let engine = AsyncKokoroEngine::new(KokoroEngine::new());
+/// engine
+///     .load_model(PathBuf::from("models/kokoro"), KokoroModelParams::default())
+///     .await?;
+///
+/// let result = engine.synthesize("Hello, world!", None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AsyncKokoroEngine {
+    inner: Arc<Mutex<KokoroEngine>>,
+}
+
+impl AsyncKokoroEngine {
+    /// Wrap an engine (loaded or not) in an async handle.
+    pub fn new(engine: KokoroEngine) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(engine)),
+        }
+    }
+
+    /// Load a model from the specified path with custom parameters.
+    pub async fn load_model(
+        &self,
+        model_path: PathBuf,
+        params: KokoroModelParams,
+    ) -> Result<(), KokoroError> {
+        self.run_blocking(move |engine| engine.load_inner(&model_path, params))
+            .await
+    }
+
+    /// Unload the currently loaded model and free associated resources.
+    pub async fn unload_model(&self) -> Result<(), KokoroError> {
+        self.run_blocking(|engine| {
+            engine.unload_model();
+            Ok(())
+        })
+        .await
+    }
+
+    /// Synthesize speech from the given text.
+    pub async fn synthesize(
+        &self,
+        text: impl Into<String>,
+        params: Option<KokoroInferenceParams>,
+    ) -> Result<SynthesisResult, KokoroError> {
+        let text = text.into();
+        self.run_blocking(move |engine| engine.synthesize_inner(&text, params))
+            .await
+    }
+
+    /// Synthesize speech incrementally as an async stream of audio chunks.
+    ///
+    /// Synthesis runs on a blocking thread and stays at most a couple of
+    /// chunks ahead of the consumer. Dropping the stream cancels the remaining
+    /// work after the chunk in progress. Must be called from within a Tokio
+    /// runtime.
+    pub fn synthesize_stream(
+        &self,
+        text: impl Into<String>,
+        params: Option<KokoroInferenceParams>,
+    ) -> SynthesisChunkStream {
+        let text = text.into();
+        let inner = Arc::clone(&self.inner);
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER_CHUNKS);
+
+        tokio::task::spawn_blocking(move || {
+            let mut engine = lock_engine(&inner);
+            let stream = match engine.stream_inner(&text, params) {
+                Ok(stream) => stream,
+                Err(e) => {
+                    let _ = tx.blocking_send(Err(e));
+                    return;
+                }
+            };
+            for chunk in stream {
+                if tx.blocking_send(chunk).is_err() {
+                    log::debug!("Synthesis stream dropped, stopping early");
+                    break;
+                }
+            }
+        });
+
+        SynthesisChunkStream { rx }
+    }
+
+    /// List all available voice names (requires model to be loaded).
+    pub async fn list_voices(&self) -> Result<Vec<String>, KokoroError> {
+        self.run_blocking(|engine| {
+            Ok(engine
+                .list_voices()
+                .into_iter()
+                .map(str::to_string)
+                .collect())
+        })
+        .await
+    }
+
+    /// Describe every loaded voice, sorted by id (requires model to be
+    /// loaded).
+    pub async fn voice_infos(&self) -> Result<Vec<VoiceInfo>, KokoroError> {
+        self.run_blocking(|engine| Ok(engine.voice_infos())).await
+    }
+
+    async fn run_blocking<T, F>(&self, f: F) -> Result<T, KokoroError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut KokoroEngine) -> Result<T, KokoroError> + Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || f(&mut lock_engine(&inner)))
+            .await
+            .map_err(|e| KokoroError::Task(e.to_string()))?
+    }
+}
+
+/// A panic during a previous request leaves the engine itself in a usable
+/// state, so a poisoned lock is recovered rather than propagated.
+fn lock_engine(engine: &Mutex<KokoroEngine>) -> MutexGuard<'_, KokoroEngine> {
+    engine
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Async stream of audio chunks returned by [`AsyncKokoroEngine::synthesize_stream`].
+pub struct SynthesisChunkStream {
+    rx: mpsc::Receiver<Result<SynthesisChunk, KokoroError>>,
+}
+
+impl Stream for SynthesisChunkStream {
+    type Item = Result<SynthesisChunk, KokoroError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::Pin;
+
+    use futures_core::Stream;
+
+    use super::AsyncKokoroEngine;
+    use crate::engines::kokoro::{KokoroEngine, KokoroError};
+
+    #[tokio::test]
+    async fn synthesize_without_model_reports_not_loaded() {
+        let engine = AsyncKokoroEngine::new(KokoroEngine::new());
+        let result = engine.synthesize("Hello", None).await;
+        assert!(matches!(result, Err(KokoroError::ModelNotLoaded)));
+    }
+
+    #[tokio::test]
+    async fn stream_without_model_yields_not_loaded() {
+        let engine = AsyncKokoroEngine::new(KokoroEngine::new());
+        let mut stream = engine.synthesize_stream("Hello", None);
+        let first = std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await;
+        assert!(matches!(first, Some(Err(KokoroError::ModelNotLoaded))));
+    }
+}
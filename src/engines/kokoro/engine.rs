@@ -1,9 +1,21 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-use crate::{SynthesisEngine, SynthesisResult};
+use crate::segment::base_lang;
+use crate::{SynthesisEngine, SynthesisResult, SynthesisStream};
 
-use super::model::{KokoroError, KokoroModel, SAMPLE_RATE};
-use super::phonemizer::EspeakConfig;
+#[cfg(feature = "espeak-ffi")]
+use super::espeak_ffi::EspeakFfiPhonemizer;
+use super::language::{default_voice, detect_language, intern_language};
+use super::lexicon::Lexicon;
+use super::model::{KokoroError, KokoroModel, KokoroStream, DEFAULT_MAX_CHUNK_CHARS, STYLE_DIM};
+use super::normalize::{chunked_text_parts, normalize_text, set_language, NormalizedText};
+use super::phonemizer::{voice_prefix_lang, Phonemizer};
+#[cfg(not(feature = "espeak-ffi"))]
+use super::phonemizer::{EspeakConfig, EspeakPhonemizer};
+use super::ssml::{self, SsmlDocument};
+use super::text_stream::TextStreamSynthesizer;
+use super::voices::{VoiceInfo, VoiceMix, VoiceStore};
 
 /// Parameters for configuring Kokoro model loading.
 #[derive(Debug, Clone, Default)]
@@ -20,17 +32,40 @@ pub struct KokoroModelParams {
     /// Always write to a writable location (e.g. app data dir); bundled resource
     /// directories may be read-only.
     pub optimized_model_cache_path: Option<PathBuf>,
+    /// Voice name prefixes mapped to espeak-ng language codes, e.g.
+    /// `"kf" => "ko"` for a voice pack with new prefixes or
+    /// `"af_brand" => "en-gb"` for one voice. Applied before voice metadata
+    /// and the official prefixes; the longest matching prefix wins. Loading
+    /// fails with [`KokoroError::UnknownLanguage`] for codes espeak-ng does
+    /// not know.
+    pub voice_languages: HashMap<String, String>,
 }
 
 /// Parameters for configuring a Kokoro synthesis request.
 #[derive(Debug, Clone)]
 pub struct KokoroInferenceParams {
-    /// Voice name (e.g. `"af_heart"`, `"bf_emma"`, `"jf_alpha"`).
+    /// Voice name (e.g. `"af_heart"`, `"bf_emma"`, `"jf_alpha"`), or a
+    /// weighted blend of voices such as `"af_heart:0.6+bf_emma:0.4"` (see
+    /// [`VoiceMix`](super::VoiceMix)).
     pub voice: String,
     /// Speech speed multiplier. Range: 0.5–2.0, default 1.0.
     pub speed: f32,
     /// Override the style vector index. `None` = auto (uses phoneme token count).
     pub style_index: Option<usize>,
+    /// Spell out numbers, currencies, dates, times, units, abbreviations and
+    /// version strings before phonemization. Off by default, so text is read
+    /// as before unless requested. See [`KokoroEngine::normalize_text`] for
+    /// the rewritten text.
+    pub normalize: bool,
+    /// Longest text chunk, in characters, synthesized in one model call.
+    /// Longer input is cut at sentence, clause or word boundaries. Default
+    /// [`DEFAULT_MAX_CHUNK_CHARS`].
+    pub max_chunk_chars: usize,
+    /// espeak-ng language code to phonemize and normalize the text in,
+    /// overriding the voice's language and language detection. Codes
+    /// espeak-ng does not know fail with [`KokoroError::UnknownLanguage`].
+    /// Default `None`.
+    pub language: Option<String>,
 }
 
 impl Default for KokoroInferenceParams {
@@ -39,6 +74,9 @@ impl Default for KokoroInferenceParams {
             voice: "af_heart".to_string(),
             speed: 1.0,
             style_index: None,
+            normalize: false,
+            max_chunk_chars: DEFAULT_MAX_CHUNK_CHARS,
+            language: None,
         }
     }
 }
@@ -46,7 +84,9 @@ impl Default for KokoroInferenceParams {
 /// Kokoro text-to-speech engine.
 ///
 /// Uses the Kokoro-82M ONNX model for high-quality, fast TTS with support
-/// for 9 languages. Requires espeak-ng for phonemization.
+/// for 9 languages. Requires espeak-ng for phonemization of plain text;
+/// [`synthesize_phonemes`](Self::synthesize_phonemes) and
+/// [`synthesize_tokens`](Self::synthesize_tokens) work without it.
 ///
 /// # Quick Start
 ///
@@ -74,10 +114,36 @@ impl Default for KokoroInferenceParams {
 /// );
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+///
+/// # Custom phonemizer
+///
+/// ```rust,no_run
+/// use tts_rs::engines::kokoro::{KokoroEngine, KokoroError, Phonemizer};
+///
+/// struct Dictionary;
+///
+/// impl Phonemizer for Dictionary {
+///     fn phonemize(&mut self, text: &str, _lang: &str) -> Result<String, KokoroError> {
+///         Ok(text
+///             .split_whitespace()
+///             .map(|word| match word.to_lowercase().as_str() {
+///                 "kokoro" => "kˈOkəɹO",
+///                 _ => "",
+///             })
+///             .collect::<Vec<_>>()
+///             .join(" "))
+///     }
+/// }
+///
+/// let engine = KokoroEngine::with_phonemizer(Dictionary);
+/// ```
 pub struct KokoroEngine {
     model: Option<KokoroModel>,
     model_path: Option<PathBuf>,
-    espeak: EspeakConfig,
+    phonemizer: Box<dyn Phonemizer>,
+    lexicon: Lexicon,
+    /// Pick voice and language from the input text
+    detect_language: bool,
 }
 
 impl Default for KokoroEngine {
@@ -88,11 +154,21 @@ impl Default for KokoroEngine {
 
 impl KokoroEngine {
     /// Create a new engine that uses `espeak-ng` from PATH.
+    ///
+    /// With the `espeak-ffi` feature, the linked libespeak-ng is used
+    /// in-process instead.
     pub fn new() -> Self {
+        #[cfg(feature = "espeak-ffi")]
+        let phonemizer: Box<dyn Phonemizer> = Box::new(EspeakFfiPhonemizer::default());
+        #[cfg(not(feature = "espeak-ffi"))]
+        let phonemizer: Box<dyn Phonemizer> = Box::new(EspeakPhonemizer::default());
+
         Self {
             model: None,
             model_path: None,
-            espeak: EspeakConfig::default(),
+            phonemizer,
+            lexicon: Lexicon::default(),
+            detect_language: false,
         }
     }
 
@@ -100,17 +176,275 @@ impl KokoroEngine {
     ///
     /// Use this when bundling espeak-ng with your application. Either path
     /// can be `None` to fall back to the system default.
-    pub fn with_espeak(
-        bin_path: Option<PathBuf>,
-        data_path: Option<PathBuf>,
-    ) -> Self {
+    ///
+    /// With the `espeak-ffi` feature the linked libespeak-ng is initialized
+    /// with `data_path` and `bin_path` is ignored.
+    pub fn with_espeak(bin_path: Option<PathBuf>, data_path: Option<PathBuf>) -> Self {
+        #[cfg(feature = "espeak-ffi")]
+        let phonemizer: Box<dyn Phonemizer> = {
+            let _ = bin_path;
+            Box::new(EspeakFfiPhonemizer::new(data_path))
+        };
+        #[cfg(not(feature = "espeak-ffi"))]
+        let phonemizer: Box<dyn Phonemizer> = Box::new(EspeakPhonemizer::new(EspeakConfig {
+            bin_path,
+            data_path,
+        }));
+
         Self {
             model: None,
             model_path: None,
-            espeak: EspeakConfig { bin_path, data_path },
+            phonemizer,
+            lexicon: Lexicon::default(),
+            detect_language: false,
+        }
+    }
+
+    /// Create a new engine that converts text to IPA with a custom
+    /// [`Phonemizer`] instead of espeak-ng.
+    pub fn with_phonemizer(phonemizer: impl Phonemizer + 'static) -> Self {
+        Self {
+            model: None,
+            model_path: None,
+            phonemizer: Box::new(phonemizer),
+            lexicon: Lexicon::default(),
+            detect_language: false,
+        }
+    }
+
+    /// Replace the phonemizer used for subsequent requests.
+    pub fn set_phonemizer(&mut self, phonemizer: Box<dyn Phonemizer>) {
+        self.phonemizer = phonemizer;
+    }
+
+    /// User pronunciations applied before phonemization.
+    pub fn lexicon(&self) -> &Lexicon {
+        &self.lexicon
+    }
+
+    /// Replace the user pronunciation lexicon.
+    ///
+    /// Words with an entry for the voice's language are spoken from the
+    /// lexicon's IPA; the rest of the text is phonemized as usual. Applies to
+    /// plain text, streaming and SSML synthesis.
+    pub fn set_lexicon(&mut self, lexicon: Lexicon) {
+        if let Some(model) = self.model.as_mut() {
+            model.set_lexicon(lexicon.clone());
+        }
+        self.lexicon = lexicon;
+    }
+
+    /// Add the entries of a lexicon file (see [`Lexicon`] for the format) to
+    /// the current lexicon. Returns the number of entries read.
+    pub fn load_lexicon(&mut self, path: &Path) -> Result<usize, KokoroError> {
+        let mut lexicon = self.lexicon.clone();
+        let count = lexicon.load_file(path)?;
+        self.set_lexicon(lexicon);
+        Ok(count)
+    }
+
+    /// Whether the language of the input is detected automatically.
+    pub fn language_detection(&self) -> bool {
+        self.detect_language
+    }
+
+    /// Detect the language of each input (see [`detect_language`]) and
+    /// synthesize it in that language. Off by default.
+    ///
+    /// A voice that speaks the detected language is kept. Otherwise the
+    /// [`default_voice`] for the language is used, or the first loaded voice
+    /// for it. Voices whose name has no known language prefix are kept and
+    /// read the text in the detected language. Applies to plain text,
+    /// streaming synthesis and [`text_stream`](Self::text_stream) sessions,
+    /// which detect each completed sentence on its own; SSML picks voices
+    /// explicitly.
+    pub fn set_language_detection(&mut self, enabled: bool) {
+        self.detect_language = enabled;
+    }
+
+    /// The espeak-ng language of a voice, or `None` if it cannot be resolved.
+    ///
+    /// Configured prefixes ([`KokoroModelParams::voice_languages`]) come
+    /// first, then the voice's metadata, then the official voice prefixes.
+    /// Voices without a language are phonemized as `"en-us"` with a warning
+    /// unless [`KokoroInferenceParams::language`] is set.
+    pub fn voice_language(&self, voice: &str) -> Option<&'static str> {
+        voice_language(self.model.as_ref(), voice)
+    }
+
+    /// Text as it will be read with `params`, after normalization.
+    ///
+    /// Normalization depends on the voice's language, or the detected one
+    /// with [`set_language_detection`](Self::set_language_detection); with
+    /// [`KokoroInferenceParams::normalize`] off the text is returned as is.
+    pub fn normalize_text(
+        &self,
+        text: &str,
+        params: Option<KokoroInferenceParams>,
+    ) -> Result<NormalizedText, KokoroError> {
+        let p = params.unwrap_or_default();
+        if p.normalize {
+            let language = p.language.as_deref().map(intern_language).transpose()?;
+            let (_, lang) = resolve_voice(
+                self.model.as_ref(),
+                self.detect_language,
+                text,
+                &p.voice,
+                language,
+            );
+            Ok(normalize_text(text, lang))
+        } else {
+            Ok(NormalizedText::unchanged(text))
+        }
+    }
+
+    /// Start a push-based synthesis session for incrementally arriving text.
+    ///
+    /// See [`TextStreamSynthesizer`] for details. The session borrows the
+    /// engine for its whole lifetime.
+    pub fn text_stream(
+        &mut self,
+        params: Option<KokoroInferenceParams>,
+    ) -> Result<TextStreamSynthesizer<'_>, KokoroError> {
+        let model = self.model.as_mut().ok_or(KokoroError::ModelNotLoaded)?;
+        TextStreamSynthesizer::new(
+            model,
+            self.phonemizer.as_mut(),
+            params.unwrap_or_default(),
+            self.detect_language,
+        )
+    }
+
+    /// Synthesize speech from an SSML document.
+    ///
+    /// `params` provides the defaults that `<voice>` and `<prosody>` elements
+    /// override; `<prosody rate>` multiplies the base speed. Word timestamps in
+    /// the result index into [`SsmlDocument::text`], and `<mark>` positions are
+    /// reported in [`SynthesisResult::marks`]. Each voice's text is read in
+    /// its language as [`voice_language`](Self::voice_language) resolves it,
+    /// or in [`KokoroInferenceParams::language`] when set.
+    pub fn synthesize_ssml(
+        &mut self,
+        ssml: &str,
+        params: Option<KokoroInferenceParams>,
+    ) -> Result<SynthesisResult, KokoroError> {
+        if self.model.is_none() {
+            return Err(KokoroError::ModelNotLoaded);
+        }
+        let p = params.unwrap_or_default();
+        let language = p.language.as_deref().map(intern_language).transpose()?;
+        let lang_of = |voice: Option<&str>| match language {
+            Some(lang) => lang,
+            None => voice_lang_or_warn(self.model.as_ref(), voice.unwrap_or(&p.voice)),
+        };
+        let doc = SsmlDocument::parse_with(ssml, &lang_of)?;
+        let model = self.model.as_mut().ok_or(KokoroError::ModelNotLoaded)?;
+
+        ssml::synthesize_document(
+            model,
+            &doc,
+            &p.voice,
+            p.speed,
+            p.style_index,
+            self.phonemizer.as_mut(),
+        )
+    }
+
+    /// Synthesize speech from IPA phonemes, skipping espeak-ng entirely.
+    ///
+    /// Useful for hand-tuned pronunciations and for running without espeak-ng
+    /// installed. The string uses the same IPA alphabet as the model vocabulary
+    /// (e.g. `"kˈOkəɹO"`); unsupported characters are dropped. Word timestamps
+    /// refer to whitespace-separated words of `ipa`.
+    pub fn synthesize_phonemes(
+        &mut self,
+        ipa: &str,
+        params: Option<KokoroInferenceParams>,
+    ) -> Result<SynthesisResult, KokoroError> {
+        let model = self.model.as_mut().ok_or(KokoroError::ModelNotLoaded)?;
+
+        let p = params.unwrap_or_default();
+        model.synthesize_phonemes(ipa, &p.voice, p.speed, p.style_index)
+    }
+
+    /// Synthesize speech from raw phoneme token IDs, skipping espeak-ng.
+    ///
+    /// IDs must come from the model vocabulary (see [`super::vocab`]);
+    /// padding tokens are added automatically. The result carries no word
+    /// timestamps.
+    pub fn synthesize_tokens(
+        &mut self,
+        ids: &[i64],
+        params: Option<KokoroInferenceParams>,
+    ) -> Result<SynthesisResult, KokoroError> {
+        let model = self.model.as_mut().ok_or(KokoroError::ModelNotLoaded)?;
+
+        let p = params.unwrap_or_default();
+        model.synthesize_tokens(ids, &p.voice, p.speed, p.style_index)
+    }
+
+    /// Add a voice from style vectors in memory, one per phoneme count.
+    ///
+    /// Replaces a loaded voice of the same name and returns `true` in that
+    /// case. Voices added at runtime last until the model is unloaded or
+    /// reloaded. Requires the model to be loaded.
+    pub fn add_voice(
+        &mut self,
+        name: &str,
+        styles: Vec<[f32; STYLE_DIM]>,
+    ) -> Result<bool, KokoroError> {
+        self.voices_mut()?.insert(name, styles)
+    }
+
+    /// Add a voice from a file: a float32 `.npy` array of shape `[N, 256]`
+    /// or `[N, 1, 256]`, or raw
+    /// little-endian `f32` values for any other extension. See
+    /// [`add_voice`](Self::add_voice).
+    pub fn add_voice_file(&mut self, name: &str, path: &Path) -> Result<bool, KokoroError> {
+        let voices = self.voices_mut()?;
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("npy"))
+        {
+            voices.load_npy(name, path)
+        } else {
+            voices.load_raw(name, path)
         }
     }
 
+    /// Add every voice of a `.npz` archive laid out like `voices-v1.0.bin`,
+    /// replacing voices of the same name. Returns the names added.
+    pub fn add_voice_archive(&mut self, path: &Path) -> Result<Vec<String>, KokoroError> {
+        self.voices_mut()?.load_npz(path)
+    }
+
+    /// Add a blend of loaded voices as a voice of its own, e.g. to save it
+    /// with [`save_voices`](Self::save_voices). See
+    /// [`add_voice`](Self::add_voice).
+    pub fn add_voice_mix(&mut self, name: &str, mix: &VoiceMix) -> Result<bool, KokoroError> {
+        let voices = self.voices_mut()?;
+        let styles = voices.mix_styles(mix)?;
+        voices.insert(name, styles)
+    }
+
+    /// Write the named voices to a `.npz` archive that
+    /// [`add_voice_archive`](Self::add_voice_archive), `np.load` and the
+    /// Python kokoro-onnx tools can read. See [`VoiceStore::save`].
+    pub fn save_voices(&self, path: &Path, names: &[&str]) -> Result<(), KokoroError> {
+        let model = self.model.as_ref().ok_or(KokoroError::ModelNotLoaded)?;
+        model.voices().save(path, names)
+    }
+
+    /// Remove a voice. Returns `true` if it existed.
+    pub fn remove_voice(&mut self, name: &str) -> Result<bool, KokoroError> {
+        Ok(self.voices_mut()?.remove(name))
+    }
+
+    fn voices_mut(&mut self) -> Result<&mut VoiceStore, KokoroError> {
+        let model = self.model.as_mut().ok_or(KokoroError::ModelNotLoaded)?;
+        Ok(model.voices_mut())
+    }
+
     /// List all available voice names (requires model to be loaded).
     pub fn list_voices(&self) -> Vec<&str> {
         self.model
@@ -118,6 +452,182 @@ impl KokoroEngine {
             .map(|m| m.list_voices())
             .unwrap_or_default()
     }
+
+    /// Describe a loaded voice: language, gender, accent, quality grade and
+    /// style vector count. See [`VoiceInfo`](super::VoiceInfo).
+    pub fn voice_info(&self, name: &str) -> Option<VoiceInfo> {
+        let model = self.model.as_ref()?;
+        let mut info = model.voices().info(name)?;
+        info.language = model.voice_language(name).map(str::to_string);
+        Some(info)
+    }
+
+    /// Describe every loaded voice, sorted by id (requires model to be
+    /// loaded).
+    pub fn voice_infos(&self) -> Vec<VoiceInfo> {
+        self.list_voices()
+            .into_iter()
+            .filter_map(|name| self.voice_info(name))
+            .collect()
+    }
+
+    /// Read a voice metadata sidecar file over the metadata loaded so far.
+    /// A `voices.json` next to the voice archive is read on load. See
+    /// [`VoiceStore::load_metadata`].
+    pub fn load_voice_metadata(&mut self, path: &Path) -> Result<usize, KokoroError> {
+        self.voices_mut()?.load_metadata(path)
+    }
+
+    // Typed-error counterparts of the `SynthesisEngine` methods. The trait
+    // returns `Box<dyn Error>`, which is not `Send`; the async wrapper needs
+    // errors it can move across threads.
+
+    pub(crate) fn load_inner(
+        &mut self,
+        model_path: &Path,
+        params: KokoroModelParams,
+    ) -> Result<(), KokoroError> {
+        let mut model = KokoroModel::load(
+            model_path,
+            params.num_threads,
+            params.optimized_model_cache_path.as_deref(),
+        )?;
+        model.set_lexicon(self.lexicon.clone());
+        model.set_voice_languages(params.voice_languages)?;
+        self.model = Some(model);
+        self.model_path = Some(model_path.to_path_buf());
+        Ok(())
+    }
+
+    pub(crate) fn synthesize_inner(
+        &mut self,
+        text: &str,
+        params: Option<KokoroInferenceParams>,
+    ) -> Result<SynthesisResult, KokoroError> {
+        let p = params.unwrap_or_default();
+        let language = p.language.as_deref().map(intern_language).transpose()?;
+        let (voice, lang) = resolve_voice(
+            self.model.as_ref(),
+            self.detect_language,
+            text,
+            &p.voice,
+            language,
+        );
+        let model = self.model.as_mut().ok_or(KokoroError::ModelNotLoaded)?;
+
+        let mut chunks = chunked_text_parts(text, lang, p.normalize, p.max_chunk_chars);
+        if lang != model.voice_lang(&voice) {
+            set_language(&mut chunks, lang);
+        }
+        let mut result = model.synthesize_chunks(
+            text,
+            chunks,
+            &voice,
+            p.speed,
+            p.style_index,
+            self.phonemizer.as_mut(),
+        )?;
+        result.language = Some(lang.to_string());
+        Ok(result)
+    }
+
+    pub(crate) fn stream_inner<'a>(
+        &'a mut self,
+        text: &'a str,
+        params: Option<KokoroInferenceParams>,
+    ) -> Result<KokoroStream<'a>, KokoroError> {
+        let p = params.unwrap_or_default();
+        let language = p.language.as_deref().map(intern_language).transpose()?;
+        let (voice, lang) = resolve_voice(
+            self.model.as_ref(),
+            self.detect_language,
+            text,
+            &p.voice,
+            language,
+        );
+        let model = self.model.as_mut().ok_or(KokoroError::ModelNotLoaded)?;
+
+        Ok(model
+            .synthesize_stream(
+                text,
+                &voice,
+                p.speed,
+                p.style_index,
+                self.phonemizer.as_mut(),
+            )
+            .with_normalization(p.normalize)
+            .with_max_chunk_chars(p.max_chunk_chars)
+            .with_language(lang))
+    }
+}
+
+/// Voice and espeak-ng language to synthesize `text` with when `voice`
+/// was requested. An explicit `language` keeps the voice and wins over
+/// both the voice's language and detection (`detect`). Works without a loaded
+/// model, from the official voice prefixes.
+pub(crate) fn resolve_voice(
+    model: Option<&KokoroModel>,
+    detect: bool,
+    text: &str,
+    voice: &str,
+    language: Option<&'static str>,
+) -> (String, &'static str) {
+    if let Some(lang) = language {
+        return (voice.to_string(), lang);
+    }
+    let voice_lang = voice_language(model, voice);
+    let detected = if detect { detect_language(text) } else { None };
+    let Some(detected) = detected else {
+        return (voice.to_string(), voice_lang_or_warn(model, voice));
+    };
+    match voice_lang {
+        Some(lang) if base_lang(lang) == base_lang(detected) => (voice.to_string(), lang),
+        Some(_) => {
+            let voices = model.map(KokoroModel::list_voices).unwrap_or_default();
+            let chosen = default_voice(detected)
+                .filter(|default| voices.contains(default))
+                .or_else(|| {
+                    voices
+                        .iter()
+                        .copied()
+                        .find(|name| voice_language(model, name) == Some(detected))
+                });
+            match chosen {
+                Some(chosen) => {
+                    log::info!("Detected language '{detected}', using voice '{chosen}'");
+                    (chosen.to_string(), detected)
+                }
+                None => {
+                    log::warn!(
+                        "Detected language '{detected}' but no voice speaks it, \
+                         keeping '{voice}'"
+                    );
+                    (voice.to_string(), detected)
+                }
+            }
+        }
+        None => (voice.to_string(), detected),
+    }
+}
+
+/// The language of `voice`, falling back to `"en-us"` with a warning.
+fn voice_lang_or_warn(model: Option<&KokoroModel>, voice: &str) -> &'static str {
+    voice_language(model, voice).unwrap_or_else(|| {
+        log::warn!(
+            "No language known for voice '{voice}', using en-us. Map it with \
+             KokoroModelParams::voice_languages or voice metadata, or set \
+             KokoroInferenceParams::language"
+        );
+        "en-us"
+    })
+}
+
+/// [`KokoroEngine::voice_language`] for an optional model.
+fn voice_language(model: Option<&KokoroModel>, voice: &str) -> Option<&'static str> {
+    match model {
+        Some(model) => model.voice_language(voice),
+        None => voice_prefix_lang(voice),
+    }
 }
 
 impl Drop for KokoroEngine {
@@ -135,14 +645,7 @@ impl SynthesisEngine for KokoroEngine {
         model_path: &Path,
         params: Self::ModelParams,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let model = KokoroModel::load(
-            model_path,
-            params.num_threads,
-            params.optimized_model_cache_path.as_deref(),
-        )?;
-        self.model = Some(model);
-        self.model_path = Some(model_path.to_path_buf());
-        Ok(())
+        Ok(self.load_inner(model_path, params)?)
     }
 
     fn unload_model(&mut self) {
@@ -155,15 +658,15 @@ impl SynthesisEngine for KokoroEngine {
         text: &str,
         params: Option<Self::SynthesisParams>,
     ) -> Result<SynthesisResult, Box<dyn std::error::Error>> {
-        let model = self.model.as_mut().ok_or(KokoroError::ModelNotLoaded)?;
-
-        let p = params.unwrap_or_default();
-        let samples =
-            model.synthesize_text(text, &p.voice, p.speed, p.style_index, &self.espeak)?;
+        Ok(self.synthesize_inner(text, params)?)
+    }
 
-        Ok(SynthesisResult {
-            samples,
-            sample_rate: SAMPLE_RATE,
-        })
+    fn synthesize_stream<'a>(
+        &'a mut self,
+        text: &'a str,
+        params: Option<Self::SynthesisParams>,
+    ) -> Result<SynthesisStream<'a>, Box<dyn std::error::Error>> {
+        let stream = self.stream_inner(text, params)?;
+        Ok(Box::new(stream.map(|chunk| chunk.map_err(Into::into))))
     }
 }
@@ -0,0 +1,189 @@
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use super::model::KokoroError;
+use super::phonemizer::Phonemizer;
+
+/// `AUDIO_OUTPUT_SYNCHRONOUS`: no audio device is opened.
+const AUDIO_OUTPUT_SYNCHRONOUS: c_int = 2;
+/// `espeakINITIALIZE_DONT_EXIT`: report a missing data directory instead of
+/// calling `exit()`.
+const INITIALIZE_DONT_EXIT: c_int = 0x8000;
+/// `espeakCHARS_UTF8`
+const CHARS_UTF8: c_int = 1;
+/// `espeakPHONEMES_IPA`
+const PHONEMES_IPA: c_int = 0x02;
+/// `EE_OK`
+const EE_OK: c_int = 0;
+
+#[link(name = "espeak-ng")]
+extern "C" {
+    fn espeak_Initialize(
+        output: c_int,
+        buflength: c_int,
+        path: *const c_char,
+        options: c_int,
+    ) -> c_int;
+    fn espeak_SetVoiceByName(name: *const c_char) -> c_int;
+    fn espeak_TextToPhonemes(
+        textptr: *mut *const c_void,
+        textmode: c_int,
+        phonememode: c_int,
+    ) -> *const c_char;
+}
+
+/// Process-wide libespeak-ng state.
+///
+/// The library keeps global state and is not thread-safe, so every call goes
+/// through this lock. `None` until the first successful initialization.
+static LIBRARY: Mutex<Option<Library>> = Mutex::new(None);
+
+struct Library {
+    data_path: Option<PathBuf>,
+    /// Voice currently selected in the library.
+    voice: Option<String>,
+}
+
+/// [`Phonemizer`] that calls libespeak-ng in-process.
+///
+/// Produces the same IPA as the espeak-ng command line tool without spawning
+/// a process. Available with the `espeak-ffi` feature, which links against
+/// `libespeak-ng`; it is then the default for [`KokoroEngine::new`].
+///
+/// libespeak-ng is initialized once per process, on first use. All instances
+/// share it, and calls from different threads are serialized.
+///
+/// [`KokoroEngine::new`]: super::KokoroEngine::new
+#[derive(Debug, Clone, Default)]
+pub struct EspeakFfiPhonemizer {
+    data_path: Option<PathBuf>,
+}
+
+impl EspeakFfiPhonemizer {
+    /// Create a phonemizer using the given espeak-ng-data directory, or the
+    /// library's compiled-in default when `None`.
+    ///
+    /// The data path only takes effect if libespeak-ng has not been
+    /// initialized yet in this process.
+    pub fn new(data_path: Option<PathBuf>) -> Self {
+        Self { data_path }
+    }
+
+    /// The espeak-ng-data directory requested for initialization.
+    pub fn data_path(&self) -> Option<&Path> {
+        self.data_path.as_deref()
+    }
+}
+
+impl Phonemizer for EspeakFfiPhonemizer {
+    fn phonemize(&mut self, text: &str, lang: &str) -> Result<String, KokoroError> {
+        let mut guard = lock_library();
+        let library = initialize(&mut guard, self.data_path.as_deref())?;
+
+        if library.voice.as_deref() != Some(lang) {
+            let name = c_string(lang);
+            // SAFETY: `name` is a valid NUL-terminated string and the library
+            // lock is held.
+            if unsafe { espeak_SetVoiceByName(name.as_ptr()) } != EE_OK {
+                library.voice = None;
+                return Err(KokoroError::PhonemizerFailed(format!(
+                    "libespeak-ng has no voice for language '{lang}'"
+                )));
+            }
+            library.voice = Some(lang.to_string());
+        }
+
+        let text = c_string(text);
+        let mut cursor = text.as_ptr().cast::<c_void>();
+        let mut clauses = Vec::new();
+        // espeak_TextToPhonemes handles one clause per call and advances the
+        // cursor, setting it to null at the end of the text.
+        while !cursor.is_null() {
+            // SAFETY: `cursor` points into `text`, which outlives the loop, and
+            // the library lock is held. The returned buffer is owned by the
+            // library and copied before the next call.
+            let phonemes = unsafe { espeak_TextToPhonemes(&mut cursor, CHARS_UTF8, PHONEMES_IPA) };
+            if phonemes.is_null() {
+                break;
+            }
+            let clause = unsafe { CStr::from_ptr(phonemes) }.to_string_lossy();
+            let clause = clause.trim();
+            if !clause.is_empty() {
+                clauses.push(clause.to_string());
+            }
+        }
+
+        Ok(clauses.join(" "))
+    }
+}
+
+/// A panic while holding the lock cannot leave the library half-configured in
+/// a way the next call does not repair, so a poisoned lock is recovered.
+fn lock_library() -> MutexGuard<'static, Option<Library>> {
+    LIBRARY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn initialize<'a>(
+    library: &'a mut Option<Library>,
+    data_path: Option<&Path>,
+) -> Result<&'a mut Library, KokoroError> {
+    if let Some(existing) = library {
+        if data_path.is_some() && existing.data_path.as_deref() != data_path {
+            log::warn!(
+                "libespeak-ng is already initialized with data path {:?}; ignoring {:?}",
+                existing.data_path,
+                data_path
+            );
+        }
+    } else {
+        let path = data_path.map(|p| c_string(&p.to_string_lossy()));
+        let path_ptr = path.as_ref().map_or(std::ptr::null(), |p| p.as_ptr());
+        // SAFETY: `path_ptr` is null or a valid NUL-terminated string, and the
+        // library lock is held.
+        let sample_rate = unsafe {
+            espeak_Initialize(AUDIO_OUTPUT_SYNCHRONOUS, 0, path_ptr, INITIALIZE_DONT_EXIT)
+        };
+        if sample_rate < 0 {
+            return Err(KokoroError::PhonemizerFailed(format!(
+                "failed to initialize libespeak-ng (data path: {data_path:?})"
+            )));
+        }
+        log::info!("Initialized libespeak-ng (data path: {data_path:?})");
+    }
+
+    Ok(library.get_or_insert_with(|| Library {
+        data_path: data_path.map(Path::to_path_buf),
+        voice: None,
+    }))
+}
+
+/// NUL bytes cannot cross the C boundary; treat them as spaces.
+fn c_string(s: &str) -> CString {
+    CString::new(s.replace('\0', " ")).expect("NUL bytes were replaced")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{initialize, lock_library, EspeakFfiPhonemizer};
+    use crate::engines::kokoro::Phonemizer;
+
+    /// Whether libespeak-ng finds its data, as the CLI tests check for the
+    /// espeak-ng binary.
+    fn espeak_available() -> bool {
+        initialize(&mut lock_library(), None).is_ok()
+    }
+
+    #[test]
+    fn phonemizes_in_process_like_the_cli() {
+        if !espeak_available() {
+            return;
+        }
+
+        let mut espeak = EspeakFfiPhonemizer::default();
+        let ipa = espeak.phonemize("America", "en-us").unwrap();
+        assert!(ipa.ends_with('ə'), "unexpected IPA {ipa:?}");
+    }
+}
@@ -0,0 +1,156 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use super::model::KokoroError;
+use super::phonemizer::{espeak_line_command, spawn_error, EspeakConfig};
+
+/// Line written after every request to mark the end of its output.
+///
+/// Reading stdin line by line, espeak-ng phonemizes each input line as its own
+/// utterance and flushes stdout afterwards, so the sentinel's phonemes arrive as a line
+/// of their own once everything before it has been answered. The text is
+/// nonsense so that no real segment produces the same output.
+const SENTINEL: &str = "7 xqzv 7";
+
+/// A long-lived espeak-ng process for one language.
+///
+/// Keeps stdin and stdout open between requests so that the process start-up
+/// cost is paid once. Output is read on a background thread, which lets
+/// requests time out instead of blocking forever on a hung process.
+#[derive(Debug)]
+pub(crate) struct EspeakWorker {
+    child: Child,
+    stdin: ChildStdin,
+    lines: Receiver<String>,
+    /// espeak-ng output for [`SENTINEL`] in this worker's language.
+    sentinel_ipa: String,
+    timeout: Duration,
+}
+
+impl EspeakWorker {
+    /// Start espeak-ng for `lang` and learn how it phonemizes the sentinel.
+    pub(crate) fn spawn(
+        lang: &str,
+        config: &EspeakConfig,
+        timeout: Duration,
+    ) -> Result<Self, KokoroError> {
+        let mut child = espeak_line_command(lang, config)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(spawn_error)?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            return Err(KokoroError::PhonemizerFailed(
+                "espeak-ng pipes unavailable".to_string(),
+            ));
+        };
+
+        let (tx, lines) = mpsc::channel();
+        let reader = std::thread::Builder::new()
+            .name(format!("espeak-ng-{lang}"))
+            .spawn(move || {
+                let mut stdout = BufReader::new(stdout);
+                let mut buf = Vec::new();
+                loop {
+                    buf.clear();
+                    match stdout.read_until(b'\n', &mut buf) {
+                        Ok(0) | Err(_) => break,
+                        Ok(_) => {
+                            let line = String::from_utf8_lossy(&buf).trim_end().to_string();
+                            if tx.send(line).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                }
+            });
+
+        let mut worker = Self {
+            child,
+            stdin,
+            lines,
+            sentinel_ipa: String::new(),
+            timeout,
+        };
+        reader?;
+
+        worker.write_payload(&format!("{SENTINEL}\n"))?;
+        let deadline = Instant::now() + timeout;
+        worker.sentinel_ipa = loop {
+            let line = worker.recv_line(deadline)?;
+            if !line.trim().is_empty() {
+                break line.trim().to_string();
+            }
+        };
+        log::debug!(
+            "Started espeak-ng worker for '{lang}' (sentinel: {:?})",
+            worker.sentinel_ipa
+        );
+        Ok(worker)
+    }
+
+    /// Phonemize segments, one input line each, and return every output line
+    /// produced before the sentinel.
+    ///
+    /// Usually there is one output line per segment; callers must handle a
+    /// mismatch. On error the worker is in an unknown state and should be
+    /// dropped.
+    pub(crate) fn request(&mut self, segments: &[&str]) -> Result<Vec<String>, KokoroError> {
+        let mut payload = String::new();
+        for segment in segments {
+            // A line break inside a segment would shift the output framing.
+            payload.extend(segment.chars().map(|ch| match ch {
+                '\n' | '\r' => ' ',
+                ch => ch,
+            }));
+            payload.push('\n');
+        }
+        payload.push_str(SENTINEL);
+        payload.push('\n');
+
+        let deadline = Instant::now() + self.timeout;
+        self.write_payload(&payload)?;
+
+        let mut output = Vec::with_capacity(segments.len());
+        loop {
+            let line = self.recv_line(deadline)?;
+            if line.trim() == self.sentinel_ipa {
+                return Ok(output);
+            }
+            output.push(line);
+        }
+    }
+
+    fn write_payload(&mut self, payload: &str) -> Result<(), KokoroError> {
+        self.stdin
+            .write_all(payload.as_bytes())
+            .and_then(|()| self.stdin.flush())
+            .map_err(|e| {
+                KokoroError::PhonemizerFailed(format!("espeak-ng worker stopped reading: {e}"))
+            })
+    }
+
+    fn recv_line(&self, deadline: Instant) -> Result<String, KokoroError> {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        self.lines.recv_timeout(remaining).map_err(|e| match e {
+            RecvTimeoutError::Timeout => KokoroError::PhonemizerFailed(format!(
+                "espeak-ng did not respond within {:?}",
+                self.timeout
+            )),
+            RecvTimeoutError::Disconnected => {
+                KokoroError::PhonemizerFailed("espeak-ng worker exited unexpectedly".to_string())
+            }
+        })
+    }
+}
+
+impl Drop for EspeakWorker {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
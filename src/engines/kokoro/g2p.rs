@@ -0,0 +1,1089 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use super::model::KokoroError;
+use super::phonemizer::Phonemizer;
+
+/// Built-in lexicon, see the header of the file for its format.
+const BUILTIN_LEXICON: &str = include_str!("g2p_lexicon.txt");
+
+/// English accent to produce phonemes for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnglishAccent {
+    /// General American (`en-us`, `af_`/`am_` voices)
+    American,
+    /// Received Pronunciation (`en-gb`, `bf_`/`bm_` voices)
+    British,
+}
+
+impl EnglishAccent {
+    /// Accent for an espeak-ng language code, or `None` for non-English codes.
+    pub fn from_lang(lang: &str) -> Option<Self> {
+        let lang = lang.to_ascii_lowercase();
+        if lang == "en-gb" || lang.starts_with("en-gb-") {
+            Some(Self::British)
+        } else if lang == "en" || lang.starts_with("en-") {
+            Some(Self::American)
+        } else {
+            None
+        }
+    }
+}
+
+/// Pure-Rust English grapheme-to-phoneme converter.
+///
+/// Produces the misaki-style phonemes Kokoro was trained on, so American and
+/// British voices work without espeak-ng. Words are resolved in order from:
+///
+/// 1. lexicons loaded with [`load_lexicon`](Self::load_lexicon),
+/// 2. a built-in lexicon of common words,
+/// 3. the built-in lexicon plus an inflection (`-s`, `-ed`, `-ing`, `-ly`),
+/// 4. letter-to-sound rules.
+///
+/// Numbers are read as cardinals (or ordinals for `1st`, `2nd`, ...) and short
+/// all-caps words are spelled out. Other languages are passed to the fallback
+/// phonemizer if one is set, and rejected otherwise.
+///
+/// ```rust,no_run
+/// use tts_rs::engines::kokoro::{EnglishG2p, EspeakPhonemizer, KokoroEngine};
+///
+/// // English without espeak-ng; other languages still go through espeak-ng.
+/// let g2p = EnglishG2p::new().with_fallback(EspeakPhonemizer::default());
+/// let engine = KokoroEngine::with_phonemizer(g2p);
+/// ```
+#[derive(Default)]
+pub struct EnglishG2p {
+    /// Entries loaded at runtime, consulted before the built-in lexicon.
+    lexicon: HashMap<(EnglishAccent, String), String>,
+    fallback: Option<Box<dyn Phonemizer>>,
+}
+
+impl fmt::Debug for EnglishG2p {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnglishG2p")
+            .field("lexicon_entries", &self.lexicon.len())
+            .field("has_fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+impl EnglishG2p {
+    /// Create a converter using only the built-in lexicon and rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Phonemize non-English languages with `fallback` instead of failing.
+    pub fn with_fallback(mut self, fallback: impl Phonemizer + 'static) -> Self {
+        self.fallback = Some(Box::new(fallback));
+        self
+    }
+
+    /// Load a misaki-format JSON lexicon (e.g. `us_gold.json`, `gb_gold.json`).
+    ///
+    /// Values are either a phoneme string or an object of part-of-speech
+    /// variants, from which `DEFAULT` (or the first variant) is used. Entries
+    /// take precedence over the built-in lexicon. Returns the number of
+    /// entries loaded.
+    pub fn load_lexicon(
+        &mut self,
+        path: &Path,
+        accent: EnglishAccent,
+    ) -> Result<usize, KokoroError> {
+        let content = std::fs::read_to_string(path)?;
+        let entries: HashMap<String, serde_json::Value> = serde_json::from_str(&content)
+            .map_err(|e| KokoroError::Lexicon(format!("{}: {e}", path.display())))?;
+
+        let mut loaded = 0;
+        for (word, value) in entries {
+            let phonemes = match &value {
+                serde_json::Value::String(s) => Some(s.as_str()),
+                serde_json::Value::Object(variants) => variants
+                    .get("DEFAULT")
+                    .and_then(|v| v.as_str())
+                    .or_else(|| variants.values().find_map(|v| v.as_str())),
+                _ => None,
+            };
+            if let Some(phonemes) = phonemes {
+                self.lexicon
+                    .insert((accent, word.to_lowercase()), phonemes.to_string());
+                loaded += 1;
+            }
+        }
+        log::info!("Loaded {loaded} lexicon entries from {}", path.display());
+        Ok(loaded)
+    }
+
+    /// Phonemes for a single word (or number) in the given accent.
+    ///
+    /// Returns an empty string for tokens without letters or digits.
+    pub fn word_phonemes(&self, word: &str, accent: EnglishAccent) -> String {
+        let words: Vec<String> = split_token(word)
+            .iter()
+            .map(|piece| self.piece_phonemes(piece, accent))
+            .filter(|ipa| !ipa.is_empty())
+            .collect();
+        words.join(" ")
+    }
+
+    /// Phonemes for a run of letters, digits and apostrophes.
+    fn piece_phonemes(&self, piece: &str, accent: EnglishAccent) -> String {
+        if piece.starts_with(|c: char| c.is_ascii_digit()) {
+            return match spell_number(piece) {
+                Some(words) => words
+                    .split(' ')
+                    .map(|w| self.piece_phonemes(w, accent))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                // Mixed letters and digits ("mp3", "4k"): read each run.
+                None => split_alphanumeric(piece)
+                    .into_iter()
+                    .map(|run| self.piece_phonemes(run, accent))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            };
+        }
+        if piece.contains(|c: char| c.is_ascii_digit()) {
+            return split_alphanumeric(piece)
+                .into_iter()
+                .map(|run| self.piece_phonemes(run, accent))
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+
+        let lower = piece.to_lowercase();
+        if let Some(ipa) = self.lookup(&lower, accent) {
+            return ipa;
+        }
+
+        let is_acronym = piece.chars().count() > 1 && piece.chars().all(|c| c.is_ascii_uppercase());
+        let has_vowel = lower.contains(['a', 'e', 'i', 'o', 'u', 'y']);
+        if is_acronym && piece.len() <= 3 || !has_vowel || lower.len() == 1 {
+            return localize(&spell_letters(&lower), accent);
+        }
+
+        if let Some(ipa) = self.inflected(&lower, accent) {
+            return ipa;
+        }
+        localize(&letters_to_phonemes(&lower), accent)
+    }
+
+    fn lookup(&self, word: &str, accent: EnglishAccent) -> Option<String> {
+        if let Some(ipa) = self.lexicon.get(&(accent, word.to_string())) {
+            return Some(ipa.clone());
+        }
+        builtin_lexicon()
+            .get(word)
+            .map(|entry| match (accent, entry.british) {
+                (EnglishAccent::British, Some(gb)) => gb.to_string(),
+                _ => localize(entry.american, accent),
+            })
+    }
+
+    /// Resolve regular inflections of lexicon words.
+    fn inflected(&self, word: &str, accent: EnglishAccent) -> Option<String> {
+        let find = |bases: &[String]| bases.iter().find_map(|base| self.lookup(base, accent));
+
+        if let Some(stem) = word.strip_suffix("'s").or_else(|| word.strip_suffix("s'")) {
+            return find(&[stem.to_string()]).map(|ipa| add_plural(&ipa));
+        }
+        if let Some(stem) = word.strip_suffix("ies") {
+            if let Some(ipa) = find(&[format!("{stem}y")]) {
+                return Some(add_plural(&ipa));
+            }
+        }
+        if let Some(stem) = word.strip_suffix('s').filter(|s| !s.ends_with('s')) {
+            let mut bases = vec![stem.to_string()];
+            if let Some(stem) = stem.strip_suffix('e') {
+                bases.push(stem.to_string());
+            }
+            if let Some(ipa) = find(&bases) {
+                return Some(add_plural(&ipa));
+            }
+        }
+        if let Some(stem) = word.strip_suffix("ied") {
+            if let Some(ipa) = find(&[format!("{stem}y")]) {
+                return Some(format!("{ipa}d"));
+            }
+        }
+        if let Some(stem) = word.strip_suffix("ed") {
+            if let Some(ipa) = find(&stem_candidates(stem)) {
+                return Some(add_past(&ipa));
+            }
+        }
+        if let Some(stem) = word.strip_suffix("ing") {
+            if let Some(ipa) = find(&stem_candidates(stem)) {
+                return Some(format!("{ipa}ɪŋ"));
+            }
+        }
+        if let Some(stem) = word.strip_suffix("ly") {
+            if let Some(ipa) = find(&[stem.to_string()]) {
+                return Some(format!("{ipa}li"));
+            }
+        }
+        None
+    }
+}
+
+impl Phonemizer for EnglishG2p {
+    fn phonemize(&mut self, text: &str, lang: &str) -> Result<String, KokoroError> {
+        let Some(accent) = EnglishAccent::from_lang(lang) else {
+            return match self.fallback.as_mut() {
+                Some(fallback) => fallback.phonemize(text, lang),
+                None => Err(KokoroError::PhonemizerFailed(format!(
+                    "EnglishG2p cannot phonemize language '{lang}' and has no fallback"
+                ))),
+            };
+        };
+
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let mut words: Vec<String> = tokens
+            .iter()
+            .map(|token| self.word_phonemes(token, accent))
+            .collect();
+
+        // "the" is pronounced /ði/ before a vowel sound.
+        for i in 0..words.len().saturating_sub(1) {
+            let next_is_vowel = words[i + 1]
+                .chars()
+                .find(|c| !is_stress(*c))
+                .is_some_and(is_vowel);
+            if next_is_vowel && tokens[i].eq_ignore_ascii_case("the") {
+                words[i] = "ði".to_string();
+            }
+        }
+
+        words.retain(|word| !word.is_empty());
+        Ok(words.join(" "))
+    }
+}
+
+struct LexiconEntry {
+    american: &'static str,
+    british: Option<&'static str>,
+}
+
+fn builtin_lexicon() -> &'static HashMap<&'static str, LexiconEntry> {
+    static LEXICON: OnceLock<HashMap<&'static str, LexiconEntry>> = OnceLock::new();
+    LEXICON.get_or_init(|| {
+        BUILTIN_LEXICON
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let word = fields.next()?;
+                let american = fields.next()?;
+                Some((
+                    word,
+                    LexiconEntry {
+                        american,
+                        british: fields.next(),
+                    },
+                ))
+            })
+            .collect()
+    })
+}
+
+/// Split a whitespace-delimited token into readable pieces.
+///
+/// Letters, digits and inner apostrophes stay together, as do `.` and `,`
+/// between digits. A few symbols are read as words; other characters
+/// (hyphens, slashes, quotes) only separate pieces.
+fn split_token(token: &str) -> Vec<String> {
+    let chars: Vec<char> = token
+        .chars()
+        .map(|c| if c == '’' { '\'' } else { c })
+        .collect();
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    for (i, &ch) in chars.iter().enumerate() {
+        let between_digits = |i: usize| {
+            i > 0
+                && chars[i - 1].is_ascii_digit()
+                && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())
+        };
+        if ch.is_alphanumeric() || ch == '\'' || (matches!(ch, '.' | ',') && between_digits(i)) {
+            current.push(ch);
+            continue;
+        }
+        push_piece(&mut pieces, &mut current);
+        let word = match ch {
+            '&' => "and",
+            '%' => "percent",
+            '+' => "plus",
+            '@' => "at",
+            _ => continue,
+        };
+        pieces.push(word.to_string());
+    }
+    push_piece(&mut pieces, &mut current);
+    pieces
+}
+
+fn push_piece(pieces: &mut Vec<String>, current: &mut String) {
+    let piece = current.trim_matches('\'');
+    if !piece.is_empty() {
+        pieces.push(piece.to_string());
+    }
+    current.clear();
+}
+
+/// Split into alternating runs of digits and non-digits.
+fn split_alphanumeric(piece: &str) -> Vec<&str> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for (i, ch) in piece.char_indices().skip(1) {
+        let prev = piece[..i].chars().next_back().unwrap_or(ch);
+        if prev.is_ascii_digit() != ch.is_ascii_digit() {
+            runs.push(&piece[start..i]);
+            start = i;
+        }
+    }
+    runs.push(&piece[start..]);
+    runs
+}
+
+/// Candidate base forms for a stem left after removing `-ed` or `-ing`.
+fn stem_candidates(stem: &str) -> Vec<String> {
+    let mut bases = vec![stem.to_string(), format!("{stem}e")];
+    let mut chars = stem.chars().rev();
+    if let (Some(a), Some(b)) = (chars.next(), chars.next()) {
+        if a == b && !is_vowel_letter(a) {
+            bases.push(stem[..stem.len() - a.len_utf8()].to_string());
+        }
+    }
+    bases
+}
+
+fn add_plural(ipa: &str) -> String {
+    match ipa.chars().last() {
+        Some('s' | 'z' | 'ʃ' | 'ʒ' | 'ʧ' | 'ʤ') => format!("{ipa}ᵻz"),
+        Some('p' | 't' | 'T' | 'k' | 'f' | 'θ') => format!("{ipa}s"),
+        _ => format!("{ipa}z"),
+    }
+}
+
+fn add_past(ipa: &str) -> String {
+    match ipa.chars().last() {
+        Some('t' | 'T' | 'd') => format!("{ipa}ᵻd"),
+        Some('p' | 'k' | 'f' | 'θ' | 's' | 'ʃ' | 'ʧ') => format!("{ipa}t"),
+        _ => format!("{ipa}d"),
+    }
+}
+
+const LETTER_NAMES: [&str; 26] = [
+    "ˈA",
+    "bˈi",
+    "sˈi",
+    "dˈi",
+    "ˈi",
+    "ˈɛf",
+    "ʤˈi",
+    "ˈAʧ",
+    "ˈI",
+    "ʤˈA",
+    "kˈA",
+    "ˈɛl",
+    "ˈɛm",
+    "ˈɛn",
+    "ˈO",
+    "pˈi",
+    "kjˈu",
+    "ˈɑɹ",
+    "ˈɛs",
+    "tˈi",
+    "jˈu",
+    "dˈʌbᵊljˌu",
+    "ˈɛks",
+    "wˈI",
+    "zˈi",
+    "zˈi",
+];
+
+/// Spell out letters as one word, with primary stress on the last letter.
+fn spell_letters(word: &str) -> String {
+    let names: Vec<&str> = word
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| LETTER_NAMES[(c as u8 - b'a') as usize])
+        .collect();
+    let last = names.len().saturating_sub(1);
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            if i == last {
+                name.to_string()
+            } else {
+                name.replace('ˈ', "ˌ")
+            }
+        })
+        .collect()
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Spell a number in words: cardinals (`1,204`), decimals (`3.14`) and
+/// ordinals (`21st`). Returns `None` for anything else.
+pub(crate) fn spell_number(token: &str) -> Option<String> {
+    let lower = token.to_ascii_lowercase();
+    let (digits, ordinal) = match ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| lower.strip_suffix(suffix))
+    {
+        Some(digits) => (digits, true),
+        None => (lower.as_str(), false),
+    };
+    if digits.is_empty()
+        || !digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ',' || c == '.')
+    {
+        return None;
+    }
+
+    let (int_part, frac_part) = match digits.split_once('.') {
+        Some((int_part, frac)) if !ordinal && !frac.contains(['.', ',']) => (int_part, Some(frac)),
+        Some(_) => return None,
+        None => (digits, None),
+    };
+    let int_digits: String = int_part.chars().filter(|c| *c != ',').collect();
+    if int_digits.is_empty() {
+        return None;
+    }
+
+    let mut words = if int_digits.len() > 1 && int_digits.starts_with('0') || int_digits.len() > 15
+    {
+        spell_digits(&int_digits)
+    } else {
+        cardinal_words(int_digits.parse().ok()?)
+    };
+    if ordinal {
+        words = ordinal_words(&words);
+    }
+    if let Some(frac) = frac_part {
+        words.push_str(" point ");
+        words.push_str(&spell_digits(frac));
+    }
+    Some(words)
+}
+
+/// Digits read one at a time: `"071"` -> `"zero seven one"`.
+pub(crate) fn spell_digits(digits: &str) -> String {
+    digits
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|d| ONES[d as usize])
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// English cardinal in words, e.g. `1204` -> `"one thousand two hundred four"`.
+pub(crate) fn cardinal_words(n: u64) -> String {
+    const SCALES: [(u64, &str); 4] = [
+        (1_000_000_000_000, "trillion"),
+        (1_000_000_000, "billion"),
+        (1_000_000, "million"),
+        (1_000, "thousand"),
+    ];
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+
+    let mut words = Vec::new();
+    let mut rest = n;
+    for (scale, name) in SCALES {
+        if rest >= scale {
+            words.push(below_thousand(rest / scale));
+            words.push(name.to_string());
+            rest %= scale;
+        }
+    }
+    if rest > 0 {
+        words.push(below_thousand(rest));
+    }
+    words.join(" ")
+}
+
+fn below_thousand(n: u64) -> String {
+    let mut words = Vec::new();
+    if n >= 100 {
+        words.push(ONES[(n / 100) as usize]);
+        words.push("hundred");
+    }
+    let rest = (n % 100) as usize;
+    if rest >= 20 {
+        words.push(TENS[rest / 10]);
+        let unit = rest % 10;
+        if unit > 0 {
+            words.push(ONES[unit]);
+        }
+    } else if rest > 0 {
+        words.push(ONES[rest]);
+    }
+    words.join(" ")
+}
+
+/// Turn the last word of a cardinal into its ordinal form.
+pub(crate) fn ordinal_words(cardinal: &str) -> String {
+    let (head, last) = match cardinal.rsplit_once(' ') {
+        Some((head, last)) => (format!("{head} "), last),
+        None => (String::new(), cardinal),
+    };
+    let ordinal = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        tens if tens.ends_with('y') => format!("{}ieth", &tens[..tens.len() - 1]),
+        other => format!("{other}th"),
+    };
+    head + &ordinal
+}
+
+/// English month names, January first.
+pub(crate) const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A year as it is usually read: 1990 -> "nineteen ninety",
+/// 2005 -> "two thousand five", 2026 -> "twenty twenty six".
+pub(crate) fn year_words(year: u64) -> String {
+    let (century, rest) = (year / 100, year % 100);
+    match (century, rest) {
+        (20, 0..=9) | (10, _) => cardinal_words(year),
+        (_, 0) => format!("{} hundred", cardinal_words(century)),
+        _ => format!("{} {}", cardinal_words(century), two_digits(rest)),
+    }
+}
+
+/// Two digits of a year: 5 -> "oh five", 26 -> "twenty six".
+fn two_digits(n: u64) -> String {
+    if n < 10 {
+        format!("oh {}", cardinal_words(n))
+    } else {
+        cardinal_words(n)
+    }
+}
+
+/// A numeric date in words: month 10, day 17, year `"2026"` ->
+/// `"October seventeenth, twenty twenty six"`, or British
+/// `"the seventeenth of October twenty twenty six"`.
+///
+/// `year` is given as written so that a two-digit year reads as `"oh five"`.
+/// Either the day or the year may be missing, not both. Returns `None` for
+/// out-of-range fields.
+pub(crate) fn date_words(
+    month: u64,
+    day: Option<u64>,
+    year: Option<&str>,
+    british: bool,
+) -> Option<String> {
+    let month = MONTHS.get(usize::try_from(month).ok()?.checked_sub(1)?)?;
+    let day = match day {
+        Some(day) if (1..=31).contains(&day) => Some(ordinal_words(&cardinal_words(day))),
+        Some(_) => return None,
+        None => None,
+    };
+    let year = match year {
+        Some(year) if !year.is_empty() && year.chars().all(|c| c.is_ascii_digit()) => {
+            let n = year.parse().ok()?;
+            Some(if year.len() == 2 {
+                two_digits(n)
+            } else {
+                year_words(n)
+            })
+        }
+        Some(_) => return None,
+        None => None,
+    };
+    Some(match (day, year) {
+        (Some(day), Some(year)) if british => format!("the {day} of {month} {year}"),
+        (Some(day), Some(year)) => format!("{month} {day}, {year}"),
+        (Some(day), None) if british => format!("the {day} of {month}"),
+        (Some(day), None) => format!("{month} {day}"),
+        (None, Some(year)) => format!("{month} {year}"),
+        (None, None) => return None,
+    })
+}
+
+/// Map American misaki phonemes to British ones.
+///
+/// Handles the systematic differences: non-rhotic `r`, `oʊ` -> `əʊ`, the
+/// TRAP, LOT and THOUGHT vowels, long stressed `i`/`u` and flapped `t`.
+/// Lexical differences (BATH, CLOTH words) need explicit entries.
+fn localize(american: &str, accent: EnglishAccent) -> String {
+    if accent == EnglishAccent::American {
+        return american.to_string();
+    }
+
+    let chars: Vec<char> = american.chars().collect();
+    let vowel_after = |i: usize| {
+        chars[i..]
+            .iter()
+            .find(|c| !is_stress(**c))
+            .is_some_and(|c| is_vowel(*c))
+    };
+    let mut out = String::with_capacity(american.len() + 8);
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+        let stressed = i > 0 && is_stress(chars[i - 1]);
+        let r_next = chars.get(i + 1) == Some(&'ɹ');
+        // An `ɹ` not followed by a vowel is dropped in non-rhotic accents.
+        let coda_r = r_next && !vowel_after(i + 2);
+        match ch {
+            'T' => out.push('t'),
+            'O' => out.push('Q'),
+            'æ' => out.push('a'),
+            'ᵻ' => out.push('ɪ'),
+            'ɑ' if r_next => out.push_str("ɑː"),
+            'ɑ' => out.push('ɒ'),
+            'ɔ' | 'ɜ' => {
+                out.push(ch);
+                out.push('ː');
+            }
+            'ɛ' if coda_r => out.push_str("ɛː"),
+            'ɪ' | 'ʊ' if coda_r => {
+                out.push(ch);
+                out.push('ə');
+            }
+            'i' | 'u' if stressed => {
+                out.push(ch);
+                out.push('ː');
+            }
+            _ => out.push(ch),
+        }
+        if coda_r && matches!(ch, 'ɑ' | 'ɔ' | 'ɜ' | 'ɛ' | 'ɪ' | 'ʊ' | 'ə') {
+            i += 1;
+        }
+        i += 1;
+    }
+    out
+}
+
+fn is_stress(ch: char) -> bool {
+    matches!(ch, 'ˈ' | 'ˌ')
+}
+
+fn is_vowel(ch: char) -> bool {
+    "AIOWYQaæɑɐɒɔəɛɜɪʊʌiuᵻ".contains(ch)
+}
+
+fn is_vowel_letter(ch: char) -> bool {
+    matches!(ch, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Letter-to-sound rules after Elovitz et al., "Automatic Translation of
+/// English Text to Phonetics by Means of Letter-to-Sound Rules" (NRL Report
+/// 7948, 1976), with output in misaki symbols.
+///
+/// Each rule is `(left context, letters, right context, phonemes)`; the first
+/// matching rule for the current position wins. Context symbols:
+/// ` ` word boundary, `#` one or more vowels, `:` zero or more consonants,
+/// `^` one consonant, `.` one voiced consonant, `+` one of `e i y`,
+/// `%` a suffix (`er e es ed ing ely`), `&` a sibilant, `@` a consonant after
+/// which `u` is pronounced /u/. Other characters match literally.
+#[rustfmt::skip]
+static RULES: &[(&str, &str, &str, &str)] = &[
+    (" ", "a", " ", "ə"), (" ", "are", " ", "ɑɹ"), (" ", "ar", "o", "əɹ"), ("", "ar", "#", "ɛɹ"),
+    (" ^", "as", "#", "As"), ("", "a", "wa", "ə"), ("", "aw", "", "ɔ"), (" :", "any", "", "ɛni"),
+    ("", "a", "^+#", "A"), ("#:", "ally", "", "əli"), (" ", "al", "#", "əl"),
+    ("", "again", "", "əɡɛn"), ("#:", "ag", "e", "ɪʤ"), ("", "a", "^+:#", "æ"),
+    (" :", "a", "^+ ", "A"), ("", "a", "^%", "A"), (" ", "arr", "", "əɹ"), ("", "arr", "", "æɹ"),
+    (" :", "ar", " ", "ɑɹ"), ("", "ar", " ", "ɜɹ"), ("", "ar", "", "ɑɹ"), ("", "air", "", "ɛɹ"),
+    ("", "ai", "", "A"), ("", "ay", "", "A"), ("", "au", "", "ɔ"), ("#:", "al", " ", "əl"),
+    ("#:", "als", " ", "əlz"), ("", "alk", "", "ɔk"), ("", "al", "^", "ɔl"),
+    (" :", "able", "", "Abəl"), ("", "able", "", "əbəl"), ("", "ang", "+", "Anʤ"),
+    ("#:", "a", " ", "ə"), ("", "a", "", "æ"),
+
+    (" ", "be", "^#", "bɪ"), ("", "being", "", "biɪŋ"), (" ", "both", " ", "bOθ"),
+    (" ", "bus", "#", "bɪz"), ("", "buil", "", "bɪl"), ("", "b", "", "b"),
+
+    (" ", "ch", "^", "k"), ("^e", "ch", "", "k"), ("", "ch", "", "ʧ"), (" s", "ci", "#", "sI"),
+    ("", "ci", "a", "ʃ"), ("", "ci", "o", "ʃ"), ("", "ci", "en", "ʃ"), ("", "c", "+", "s"),
+    ("", "ck", "", "k"), ("", "com", "%", "kʌm"), ("", "c", "", "k"),
+
+    ("#:", "ded", " ", "dɪd"), (".e", "d", " ", "d"), ("#^:e", "d", " ", "t"),
+    (" ", "de", "^#", "dɪ"), (" ", "do", " ", "du"), (" ", "does", "", "dʌz"),
+    (" ", "doing", "", "duɪŋ"), (" ", "dow", "", "dW"), ("", "du", "a", "ʤu"), ("", "d", "", "d"),
+
+    ("#:", "e", " ", ""), (" :", "e", " ", "i"), ("#", "ed", " ", "d"), ("#:", "e", "d ", ""),
+    ("", "ev", "er", "ɛv"), ("", "e", "^%", "i"), ("", "eri", "#", "iɹi"), ("", "eri", "", "ɛɹɪ"),
+    ("#:", "er", "#", "ɜɹ"), ("", "er", "#", "ɛɹ"), ("", "er", "", "ɜɹ"),
+    (" ", "even", "", "ivɛn"), ("#:", "e", "w", ""), ("@", "ew", "", "u"), ("", "ew", "", "ju"),
+    ("", "e", "o", "i"), ("#:&", "es", " ", "ɪz"), ("#:", "e", "s ", ""), ("#:", "ely", " ", "li"),
+    ("#:", "ement", "", "mɛnt"), ("", "eful", "", "fʊl"), ("", "ee", "", "i"),
+    ("", "earn", "", "ɜɹn"), (" ", "ear", "^", "ɜɹ"), ("", "ead", "", "ɛd"),
+    ("#:", "ea", " ", "iə"), ("", "ea", "su", "ɛ"), ("", "ea", "", "i"), ("", "eigh", "", "A"),
+    ("", "ei", "", "i"), (" ", "eye", "", "I"), ("", "ey", "", "i"), ("", "eu", "", "ju"),
+    ("", "e", "", "ɛ"),
+
+    ("", "ful", "", "fʊl"), ("", "f", "", "f"),
+
+    ("", "giv", "", "ɡɪv"), (" ", "g", "i^", "ɡ"), ("", "ge", "t", "ɡɛ"), ("su", "gges", "", "ɡʤɛs"),
+    ("", "gg", "", "ɡ"), (" b#", "g", "", "ɡ"), ("", "g", "+", "ʤ"), ("", "great", "", "ɡɹAt"),
+    ("#", "gh", "", ""), ("", "g", "", "ɡ"),
+
+    (" ", "hav", "", "hæv"), (" ", "here", "", "hiɹ"), (" ", "hour", "", "Wɜɹ"),
+    ("", "how", "", "hW"), ("", "h", "#", "h"), ("", "h", "", ""),
+
+    (" ", "in", "", "ɪn"), (" ", "i", " ", "I"), ("", "in", "d", "In"), ("", "ier", "", "iɜɹ"),
+    ("#:r", "ied", " ", "id"), ("", "ied", " ", "Id"), ("", "ien", "", "iɛn"), ("", "ie", "t", "Iɛ"),
+    (" :", "i", "%", "I"), ("", "i", "%", "i"), ("", "ie", "", "i"), ("", "i", "^+:#", "ɪ"),
+    ("", "ir", "#", "Iɹ"), ("", "iz", "%", "Iz"), ("", "is", "%", "Iz"), ("", "i", "d%", "I"),
+    ("+^", "i", "^+", "ɪ"), ("", "i", "t%", "I"), ("#^:", "i", "^+", "ɪ"), ("", "i", "^+", "I"),
+    ("", "ir", "", "ɜɹ"), ("", "igh", "", "I"), ("", "ild", "", "Ild"), ("", "ign", " ", "In"),
+    ("", "ign", "^", "In"), ("", "ign", "%", "In"), ("", "ique", "", "ik"), ("", "i", "", "ɪ"),
+
+    ("", "j", "", "ʤ"),
+
+    (" ", "k", "n", ""), ("", "k", "", "k"),
+
+    ("", "lo", "c#", "lO"), ("l", "l", "", ""), ("#^:", "l", "%", "əl"), ("", "lead", "", "lid"),
+    ("", "l", "", "l"),
+
+    ("", "mov", "", "muv"), ("", "m", "", "m"),
+
+    ("e", "ng", "+", "nʤ"), ("", "ng", "r", "ŋɡ"), ("", "ng", "#", "ŋɡ"), ("", "ngl", "%", "ŋɡəl"),
+    ("", "ng", "", "ŋ"), ("", "nk", "", "ŋk"), (" ", "now", " ", "nW"), ("", "n", "", "n"),
+
+    ("", "of", " ", "əv"), ("", "orough", "", "ɜɹO"), ("#:", "or", " ", "ɜɹ"),
+    ("#:", "ors", " ", "ɜɹz"), ("", "or", "", "ɔɹ"), (" ", "one", "", "wʌn"), ("", "ow", "", "O"),
+    (" ", "over", "", "Ovɜɹ"), ("", "ov", "", "ʌv"), ("", "o", "^%", "O"), ("", "o", "^en", "O"),
+    ("", "o", "^i#", "O"), ("", "ol", "d", "Ol"), ("", "ought", "", "ɔt"), ("", "ough", "", "ʌf"),
+    (" ", "ou", "", "W"), ("h", "ou", "s#", "W"), ("", "ous", "", "əs"), ("", "our", "", "ɔɹ"),
+    ("", "ould", "", "ʊd"), ("^", "ou", "^l", "ʌ"), ("", "oup", "", "up"), ("", "ou", "", "W"),
+    ("", "oy", "", "Y"), ("", "oing", "", "Oɪŋ"), ("", "oi", "", "Y"), ("", "oor", "", "ɔɹ"),
+    ("", "ook", "", "ʊk"), ("", "ood", "", "ʊd"), ("", "oo", "", "u"), ("", "o", "e", "O"),
+    ("", "o", " ", "O"), ("", "oa", "", "O"), (" ", "only", "", "Onli"), (" ", "once", "", "wʌns"),
+    ("", "on't", "", "Ont"), ("c", "o", "n", "ɑ"), ("", "o", "ng", "ɔ"), (" :^", "o", "n", "ʌ"),
+    ("i", "on", "", "ən"), ("#:", "on", " ", "ən"), ("#^", "on", "", "ən"), ("", "o", "st ", "O"),
+    ("", "of", "^", "ɔf"), ("", "other", "", "ʌðɜɹ"), ("", "oss", " ", "ɔs"),
+    ("#^:", "om", "", "ʌm"), ("", "o", "", "ɑ"),
+
+    ("", "ph", "", "f"), ("", "peop", "", "pip"), ("", "pow", "", "pW"), ("", "put", " ", "pʊt"),
+    ("", "p", "", "p"),
+
+    ("", "quar", "", "kwɔɹ"), ("", "qu", "", "kw"), ("", "q", "", "k"),
+
+    (" ", "re", "^#", "ɹi"), ("", "r", "", "ɹ"),
+
+    ("", "sh", "", "ʃ"), ("#", "sion", "", "ʒən"), ("", "some", "", "sʌm"), ("#", "sur", "#", "ʒɜɹ"),
+    ("", "sur", "#", "ʃɜɹ"), ("#", "su", "#", "ʒu"), ("#", "ssu", "#", "ʃu"), ("#", "sed", " ", "zd"),
+    ("#", "s", "#", "z"), ("", "said", "", "sɛd"), ("^", "sion", "", "ʃən"), ("", "s", "s", ""),
+    (".", "s", " ", "z"), ("#:.e", "s", " ", "z"), ("#^:##", "s", " ", "z"), ("#^:#", "s", " ", "s"),
+    ("u", "s", " ", "s"), (" :#", "s", " ", "z"), (" ", "sch", "", "sk"), ("", "s", "c+", ""),
+    ("#", "sm", "", "zm"), ("#", "sn", "'", "zən"), ("", "s", "", "s"),
+
+    (" ", "the", " ", "ðə"), ("", "to", " ", "tu"), ("", "that", " ", "ðæt"), (" ", "this", " ", "ðɪs"),
+    (" ", "they", "", "ðA"), (" ", "there", "", "ðɛɹ"), ("", "ther", "", "ðɜɹ"),
+    ("", "their", "", "ðɛɹ"), (" ", "than", " ", "ðæn"), (" ", "them", " ", "ðɛm"),
+    ("", "these", " ", "ðiz"), (" ", "then", "", "ðɛn"), ("", "through", "", "θɹu"),
+    ("", "those", "", "ðOz"), ("", "though", " ", "ðO"), (" ", "thus", "", "ðʌs"), ("", "th", "", "θ"),
+    ("#:", "ted", " ", "tɪd"), ("s", "ti", "#n", "ʧ"), ("", "ti", "o", "ʃ"), ("", "ti", "a", "ʃ"),
+    ("", "tien", "", "ʃən"), ("", "tur", "#", "ʧɜɹ"), ("", "tu", "a", "ʧu"), (" ", "two", "", "tu"),
+    ("", "t", "", "t"),
+
+    (" ", "un", "i", "jun"), (" ", "un", "", "ʌn"), (" ", "upon", "", "əpɔn"), ("@", "ur", "#", "ʊɹ"),
+    ("", "ur", "#", "jʊɹ"), ("", "ur", "", "ɜɹ"), ("", "u", "^ ", "ʌ"), ("", "u", "^^", "ʌ"),
+    ("", "uy", "", "I"), (" g", "u", "#", ""), ("g", "u", "%", ""), ("g", "u", "#", "w"),
+    ("#n", "u", "", "ju"), ("@", "u", "", "u"), ("", "u", "", "ju"),
+
+    ("", "view", "", "vju"), ("", "v", "", "v"),
+
+    (" ", "were", "", "wɜɹ"), ("", "wa", "s", "wɑ"), ("", "wa", "t", "wɑ"), ("", "where", "", "wɛɹ"),
+    ("", "what", "", "wɑt"), ("", "whol", "", "hOl"), ("", "who", "", "hu"), ("", "wh", "", "w"),
+    ("", "war", "", "wɔɹ"), ("", "wor", "^", "wɜɹ"), ("", "wr", "", "ɹ"), ("", "w", "", "w"),
+
+    ("", "x", "", "ks"),
+
+    ("", "young", "", "jʌŋ"), (" ", "you", "", "ju"), (" ", "yes", "", "jɛs"), (" ", "y", "", "j"),
+    ("#:", "y", " ", "i"), ("#:", "y", "i", "i"), (" :", "y", " ", "I"), (" :", "y", "#", "I"),
+    (" :", "y", "^+:#", "ɪ"), (" :", "y", "^#", "I"), ("", "y", "", "ɪ"),
+
+    ("", "z", "", "z"),
+];
+
+/// Pronounce an unknown lowercase word with the letter-to-sound rules and
+/// a heuristic stress placement.
+fn letters_to_phonemes(word: &str) -> String {
+    let chars: Vec<char> = format!(" {word} ").chars().collect();
+    let mut ipa = String::new();
+    let mut i = 1;
+    while i < chars.len() - 1 {
+        let rule = RULES.iter().find(|(left, letters, right, _)| {
+            let letters: Vec<char> = letters.chars().collect();
+            chars[i..].starts_with(&letters)
+                && match_right(right, &chars, i + letters.len())
+                && match_left(left, &chars, i)
+        });
+        match rule {
+            Some((_, letters, _, phonemes)) => {
+                ipa.push_str(phonemes);
+                i += letters.chars().count();
+            }
+            // Characters without rules (apostrophes, non-ASCII letters).
+            None => i += 1,
+        }
+    }
+
+    // Collapse doubled consonants the rules do not handle ("tt", "ss").
+    let mut collapsed = String::with_capacity(ipa.len());
+    for ch in ipa.chars() {
+        if collapsed.ends_with(ch) && !is_vowel(ch) {
+            continue;
+        }
+        collapsed.push(ch);
+    }
+    add_stress(&collapsed, word)
+}
+
+/// Insert a primary stress mark and reduce unstressed `ɜɹ` to `əɹ`.
+fn add_stress(ipa: &str, word: &str) -> String {
+    const UNSTRESSED_PREFIXES: [&str; 10] = [
+        "be", "de", "re", "con", "com", "ex", "dis", "mis", "pre", "un",
+    ];
+    // Suffixes that put the stress on the syllable before them, with the
+    // number of vowels they contribute.
+    const STRESS_SHIFTING_SUFFIXES: [(&str, usize); 6] = [
+        ("tion", 1),
+        ("sion", 1),
+        ("cian", 1),
+        ("ical", 2),
+        ("ic", 1),
+        ("ity", 2),
+    ];
+
+    let chars: Vec<char> = ipa.chars().collect();
+    let vowels: Vec<usize> = (0..chars.len()).filter(|&i| is_vowel(chars[i])).collect();
+    let Some(&first) = vowels.first() else {
+        return ipa.to_string();
+    };
+
+    let shifted = STRESS_SHIFTING_SUFFIXES
+        .iter()
+        .find(|(suffix, _)| word.ends_with(suffix))
+        .and_then(|&(_, n)| vowels.len().checked_sub(n + 1).map(|i| vowels[i]));
+    let prefixed = (vowels.len() > 1
+        && UNSTRESSED_PREFIXES
+            .iter()
+            .any(|prefix| word.len() > prefix.len() + 2 && word.starts_with(prefix)))
+    .then(|| vowels[1]);
+    let stressed = shifted.or(prefixed).unwrap_or(first);
+
+    let mut out = String::with_capacity(ipa.len() + 2);
+    for (i, &ch) in chars.iter().enumerate() {
+        if i == stressed {
+            out.push('ˈ');
+        }
+        if ch == 'ɜ' && i != stressed && chars.get(i + 1) == Some(&'ɹ') {
+            out.push('ə');
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+fn is_consonant_letter(ch: char) -> bool {
+    ch.is_ascii_alphabetic() && !is_vowel_letter(ch)
+}
+
+/// Match a right-context pattern starting at `pos`.
+fn match_right(pattern: &str, chars: &[char], mut pos: usize) -> bool {
+    let at = |pos: usize| chars.get(pos).copied().unwrap_or(' ');
+    for p in pattern.chars() {
+        match p {
+            '#' => {
+                if !is_vowel_letter(at(pos)) {
+                    return false;
+                }
+                while is_vowel_letter(at(pos)) {
+                    pos += 1;
+                }
+            }
+            ':' => {
+                while is_consonant_letter(at(pos)) {
+                    pos += 1;
+                }
+            }
+            '^' if is_consonant_letter(at(pos)) => pos += 1,
+            '.' if "bdvgjlmnrwz".contains(at(pos)) => pos += 1,
+            '+' if matches!(at(pos), 'e' | 'i' | 'y') => pos += 1,
+            '%' => {
+                let rest: String = chars[pos.min(chars.len())..].iter().collect();
+                pos += if rest.starts_with("ing") || rest.starts_with("ely") {
+                    3
+                } else if rest.starts_with("er") || rest.starts_with("es") || rest.starts_with("ed")
+                {
+                    2
+                } else if rest.starts_with('e') {
+                    1
+                } else {
+                    return false;
+                };
+            }
+            literal if !"^.+".contains(literal) && at(pos) == literal => pos += 1,
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Match a left-context pattern ending just before `pos`.
+fn match_left(pattern: &str, chars: &[char], pos: usize) -> bool {
+    // Positions are offset by one so that "before the start" is index 0.
+    let mut pos = pos;
+    let at = |pos: usize| if pos == 0 { ' ' } else { chars[pos - 1] };
+    for p in pattern.chars().rev() {
+        match p {
+            '#' => {
+                if !is_vowel_letter(at(pos)) {
+                    return false;
+                }
+                while pos > 0 && is_vowel_letter(at(pos)) {
+                    pos -= 1;
+                }
+            }
+            ':' => {
+                while pos > 0 && is_consonant_letter(at(pos)) {
+                    pos -= 1;
+                }
+            }
+            '^' if is_consonant_letter(at(pos)) => pos -= 1,
+            '.' if "bdvgjlmnrwz".contains(at(pos)) => pos -= 1,
+            '+' if matches!(at(pos), 'e' | 'i' | 'y') => pos -= 1,
+            '&' => {
+                let prev = at(pos);
+                let pair = (pos >= 2).then(|| (at(pos - 1), prev));
+                if matches!(pair, Some(('c' | 's', 'h'))) {
+                    pos -= 2;
+                } else if "scgzxj".contains(prev) {
+                    pos -= 1;
+                } else {
+                    return false;
+                }
+            }
+            '@' => {
+                let prev = at(pos);
+                let pair = (pos >= 2).then(|| (at(pos - 1), prev));
+                if matches!(pair, Some(('t' | 'c' | 's', 'h'))) {
+                    pos -= 2;
+                } else if "tsrdlznj".contains(prev) {
+                    pos -= 1;
+                } else {
+                    return false;
+                }
+            }
+            ' ' if pos == 0 || at(pos) == ' ' => pos = pos.saturating_sub(1),
+            literal if literal != ' ' && !"^.+".contains(literal) && at(pos) == literal => pos -= 1,
+            _ => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{builtin_lexicon, localize, spell_number, EnglishAccent, EnglishG2p};
+    use crate::engines::kokoro::vocab::hardcoded_vocab;
+    use crate::engines::kokoro::Phonemizer;
+
+    #[test]
+    fn lexicon_uses_only_vocab_symbols() {
+        let vocab = hardcoded_vocab();
+        for (word, entry) in builtin_lexicon() {
+            let british = entry
+                .british
+                .map(str::to_string)
+                .unwrap_or_else(|| localize(entry.american, EnglishAccent::British));
+            for ipa in [entry.american, british.as_str()] {
+                let unknown: String = ipa.chars().filter(|c| !vocab.contains_key(c)).collect();
+                assert!(unknown.is_empty(), "{word}: {ipa:?} has {unknown:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn phonemizes_american_and_british_text() {
+        let mut g2p = EnglishG2p::new();
+        assert_eq!(
+            g2p.phonemize("Hello world, the answer", "en-us").unwrap(),
+            "həlˈO wˈɜɹld ði ˈænsəɹ"
+        );
+        assert_eq!(
+            g2p.phonemize("Hello water", "en-gb").unwrap(),
+            "həlˈQ wˈɔːtə"
+        );
+        assert!(g2p.phonemize("Bonjour", "fr").is_err());
+    }
+
+    #[test]
+    fn handles_inflections_numbers_and_acronyms() {
+        let g2p = EnglishG2p::new();
+        let us = EnglishAccent::American;
+        assert_eq!(g2p.word_phonemes("dogs", us), "dˈɔɡz");
+        assert_eq!(g2p.word_phonemes("wanted", us), "wˈɑntᵻd");
+        assert_eq!(g2p.word_phonemes("stopping", us), "stˈɑpɪŋ");
+        assert_eq!(g2p.word_phonemes("42", us), "fˈɔɹTi tˈu");
+        assert_eq!(g2p.word_phonemes("FBI", us), "ˌɛfbˌiˈI");
+        assert_eq!(spell_number("21st").as_deref(), Some("twenty first"));
+        assert_eq!(
+            spell_number("1,204.5").as_deref(),
+            Some("one thousand two hundred four point five")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_letter_to_sound_rules() {
+        let g2p = EnglishG2p::new();
+        let us = EnglishAccent::American;
+        assert_eq!(g2p.word_phonemes("blob", us), "blˈɑb");
+        assert_eq!(g2p.word_phonemes("shine", us), "ʃˈIn");
+        assert_eq!(g2p.word_phonemes("station", us), "stˈAʃən");
+    }
+}
@@ -0,0 +1,561 @@
+//! Finding stretches of text written in another language than the voice's.
+
+use std::ops::Range;
+
+use super::model::KokoroError;
+use super::phonemizer::inline_pronunciation_spans;
+use crate::segment::base_lang;
+
+/// A stretch of text to phonemize in one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LanguageRun {
+    /// Byte range in the source text, excluding any language tags.
+    pub(crate) span: Range<usize>,
+    /// espeak-ng code to phonemize with, or `None` for the voice language.
+    pub(crate) lang: Option<&'static str>,
+}
+
+/// Scripts that tell languages apart without any markup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    /// Han ideographs and Japanese kana.
+    Cjk,
+    Devanagari,
+}
+
+/// Detect the language of `text` among the languages Kokoro has voices for.
+///
+/// Returns an espeak-ng code as [`voice_lang`](super::phonemizer::voice_lang)
+/// does (`"en-us"`, `"es"`, `"fr"`, `"hi"`, `"it"`, `"ja"`, `"pt-br"` or
+/// `"cmn"`), or `None` for text without letters. The most common script
+/// decides first: Devanagari is Hindi, Han with any kana Japanese and Han
+/// alone Mandarin. Latin text is scored by common words and by letters
+/// particular to each language, and is English when nothing else matches.
+/// British English cannot be told apart by text and is reported as
+/// `"en-us"`.
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let (mut latin, mut cjk, mut kana, mut devanagari) = (0usize, 0usize, 0usize, 0usize);
+    for ch in text.chars() {
+        match script(ch) {
+            Some(Script::Latin) => latin += 1,
+            Some(Script::Cjk) => {
+                cjk += 1;
+                kana += usize::from(is_kana(ch));
+            }
+            Some(Script::Devanagari) => devanagari += 1,
+            None => {}
+        }
+    }
+    if latin + cjk + devanagari == 0 {
+        return None;
+    }
+    if devanagari >= latin.max(cjk) {
+        return Some("hi");
+    }
+    if cjk >= latin {
+        return Some(if kana > 0 { "ja" } else { "cmn" });
+    }
+    Some(latin_language(text))
+}
+
+/// Common words of the Latin-script languages, used to tell them apart.
+const LATIN_WORDS: &[(&str, &[&str])] = &[
+    (
+        "en-us",
+        &[
+            "the", "and", "is", "are", "of", "to", "in", "it", "that", "you", "this", "with",
+            "for", "was", "have", "not", "be", "on", "what", "i", "my", "we", "they",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "la", "los", "las", "de", "que", "y", "en", "un", "una", "es", "por", "con",
+            "para", "no", "se", "del", "al", "lo", "como", "pero", "muy", "está", "hola", "yo",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "de", "des", "et", "est", "un", "une", "du", "en", "que", "pour",
+            "pas", "je", "vous", "nous", "il", "elle", "avec", "dans", "ce", "sur", "qui",
+            "bonjour",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "lo", "la", "gli", "le", "di", "e", "è", "che", "un", "una", "per", "con", "non",
+            "sono", "del", "della", "nel", "ciao", "anche", "questo", "come",
+        ],
+    ),
+    (
+        "pt-br",
+        &[
+            "o", "a", "os", "as", "de", "do", "da", "dos", "das", "e", "é", "que", "um", "uma",
+            "em", "no", "na", "para", "com", "não", "por", "você", "olá", "muito", "está", "eu",
+        ],
+    ),
+];
+
+/// Letters found in only some of the Latin-script languages. A language
+/// listed twice counts double.
+fn letter_languages(ch: char) -> &'static [&'static str] {
+    match ch {
+        'ñ' | '¿' | '¡' => &["es", "es"],
+        'ã' | 'õ' => &["pt-br", "pt-br"],
+        'ç' => &["fr", "pt-br"],
+        'œ' | 'û' | 'ë' | 'ï' | 'ÿ' => &["fr"],
+        'è' | 'ù' => &["fr", "it"],
+        'ì' | 'ò' => &["it"],
+        _ => &[],
+    }
+}
+
+/// Best scoring Latin-script language of `text`.
+fn latin_language(text: &str) -> &'static str {
+    let mut scores = [0usize; LATIN_WORDS.len()];
+    let mut score = |lang: &str| {
+        if let Some(i) = LATIN_WORDS.iter().position(|&(code, _)| code == lang) {
+            scores[i] += 1;
+        }
+    };
+    let lower = text.to_lowercase();
+    for word in lower.split(|c: char| !c.is_alphanumeric()) {
+        for &(lang, words) in LATIN_WORDS {
+            if words.contains(&word) {
+                score(lang);
+            }
+        }
+    }
+    for ch in lower.chars() {
+        letter_languages(ch).iter().for_each(|lang| score(lang));
+    }
+
+    // Ties go to the earlier language, so text without clues is English.
+    let mut best = 0;
+    for (i, &count) in scores.iter().enumerate() {
+        if count > scores[best] {
+            best = i;
+        }
+    }
+    LATIN_WORDS[best].0
+}
+
+/// The voice used for `lang` when a voice has to be picked automatically.
+pub fn default_voice(lang: &str) -> Option<&'static str> {
+    let voice = match lang {
+        "en-us" | "en" => "af_heart",
+        "en-gb" => "bf_emma",
+        "es" => "ef_dora",
+        "fr" | "fr-fr" => "ff_siwis",
+        "hi" => "hf_alpha",
+        "it" => "if_sara",
+        "ja" => "jf_alpha",
+        "pt-br" | "pt" => "pf_dora",
+        "cmn" | "zh" => "zf_xiaobei",
+        _ => return None,
+    };
+    Some(voice)
+}
+
+/// Split `text` into runs for a voice speaking `lang`.
+///
+/// Text between `[xx]` and `[/xx]` tags is phonemized in language `xx`
+/// (`en`, `en-us`, `en-gb`, `es`, `fr`, `hi`, `it`, `ja`, `pt`, `pt-br`,
+/// `zh`, `cmn`); the tags themselves are not spoken. Untagged letters in a
+/// script the voice language is not written in are assigned by script:
+/// Latin to English, kana (with any adjacent Han) to Japanese, Han alone to
+/// Mandarin and Devanagari to Hindi. Digits, punctuation and whitespace
+/// between two letters of one run belong to that run and to the voice
+/// language otherwise. Runs cover all of `text` except the tags.
+pub(crate) fn language_runs(text: &str, lang: &str) -> Vec<LanguageRun> {
+    let inline = inline_pronunciation_spans(text);
+    let mut runs = Vec::new();
+    let mut start = 0usize;
+    let mut from = 0usize;
+    while let Some(offset) = text[from..].find('[') {
+        let idx = from + offset;
+        let Some(tag) = language_tag(text, idx, &inline) else {
+            from = idx + 1;
+            continue;
+        };
+        script_runs(text, start..idx, lang, &inline, &mut runs);
+        if tag.inner.start < tag.inner.end {
+            let same = base_lang(tag.lang) == base_lang(lang);
+            runs.push(LanguageRun {
+                span: tag.inner,
+                lang: (!same).then_some(tag.lang),
+            });
+        }
+        start = tag.end;
+        from = tag.end;
+    }
+    script_runs(text, start..text.len(), lang, &inline, &mut runs);
+    runs
+}
+
+/// A `[xx]...[/xx]` span.
+struct LanguageTag {
+    lang: &'static str,
+    /// Byte range of the tagged text.
+    inner: Range<usize>,
+    /// Byte offset just past the closing tag.
+    end: usize,
+}
+
+/// Parse a language tag pair opening at byte `idx`, if there is one.
+fn language_tag(text: &str, idx: usize, inline: &[Range<usize>]) -> Option<LanguageTag> {
+    if inline.iter().any(|span| span.contains(&idx)) {
+        return None;
+    }
+    let rest = &text[idx + 1..];
+    let code = &rest[..rest.find(']')?];
+    let lang = tag_lang(code)?;
+    let inner_start = idx + code.len() + "[]".len();
+    let close = format!("[/{code}]");
+    let inner_len = text[inner_start..].find(&close)?;
+    Some(LanguageTag {
+        lang,
+        inner: inner_start..inner_start + inner_len,
+        end: inner_start + inner_len + close.len(),
+    })
+}
+
+/// Language codes of the voices bundled with espeak-ng.
+const ESPEAK_LANGUAGES: &[&str] = &[
+    "af",
+    "am",
+    "an",
+    "ar",
+    "as",
+    "az",
+    "ba",
+    "be",
+    "bg",
+    "bn",
+    "bpy",
+    "bs",
+    "ca",
+    "chr",
+    "cmn",
+    "cmn-latn-pinyin",
+    "cs",
+    "cv",
+    "cy",
+    "da",
+    "de",
+    "el",
+    "en",
+    "en-029",
+    "en-gb",
+    "en-gb-scotland",
+    "en-gb-x-gbclan",
+    "en-gb-x-gbcwmd",
+    "en-gb-x-rp",
+    "en-us",
+    "en-us-nyc",
+    "eo",
+    "es",
+    "es-419",
+    "et",
+    "eu",
+    "fa",
+    "fa-latn",
+    "fi",
+    "fr",
+    "fr-be",
+    "fr-ch",
+    "ga",
+    "gd",
+    "gn",
+    "grc",
+    "gu",
+    "hak",
+    "haw",
+    "he",
+    "hi",
+    "hr",
+    "ht",
+    "hu",
+    "hy",
+    "hyw",
+    "ia",
+    "id",
+    "io",
+    "is",
+    "it",
+    "ja",
+    "jbo",
+    "ka",
+    "kk",
+    "kl",
+    "kn",
+    "ko",
+    "kok",
+    "ku",
+    "ky",
+    "la",
+    "lb",
+    "lfn",
+    "lt",
+    "ltg",
+    "lv",
+    "mi",
+    "mk",
+    "ml",
+    "mr",
+    "ms",
+    "mt",
+    "mto",
+    "my",
+    "nb",
+    "nci",
+    "ne",
+    "nl",
+    "nog",
+    "om",
+    "or",
+    "pa",
+    "pap",
+    "piqd",
+    "pl",
+    "pt",
+    "pt-br",
+    "py",
+    "qdb",
+    "qu",
+    "quc",
+    "qya",
+    "ro",
+    "ru",
+    "ru-lv",
+    "sd",
+    "shn",
+    "si",
+    "sjn",
+    "sk",
+    "sl",
+    "smj",
+    "sq",
+    "sr",
+    "sv",
+    "sw",
+    "ta",
+    "te",
+    "th",
+    "tk",
+    "tn",
+    "tr",
+    "tt",
+    "ug",
+    "uk",
+    "ur",
+    "uz",
+    "vi",
+    "vi-vn-x-central",
+    "vi-vn-x-south",
+    "yue",
+];
+
+/// A language code with a `'static` lifetime, as the phonemization pipeline
+/// passes them around.
+///
+/// Accepts the codes of espeak-ng's bundled voices in any case; anything else
+/// is rejected with [`KokoroError::UnknownLanguage`].
+pub(crate) fn intern_language(code: &str) -> Result<&'static str, KokoroError> {
+    let code = code.trim();
+    ESPEAK_LANGUAGES
+        .iter()
+        .copied()
+        .find(|lang| lang.eq_ignore_ascii_case(code))
+        .ok_or_else(|| KokoroError::UnknownLanguage(code.to_string()))
+}
+
+/// espeak-ng code for a language tag.
+fn tag_lang(code: &str) -> Option<&'static str> {
+    let lang = match code.to_ascii_lowercase().as_str() {
+        "en" | "en-us" => "en-us",
+        "en-gb" => "en-gb",
+        "es" => "es",
+        "fr" => "fr",
+        "hi" => "hi",
+        "it" => "it",
+        "ja" => "ja",
+        "pt" | "pt-br" => "pt-br",
+        "zh" | "cmn" => "cmn",
+        _ => return None,
+    };
+    Some(lang)
+}
+
+fn script(ch: char) -> Option<Script> {
+    match ch {
+        'a'..='z' | 'A'..='Z' | '\u{c0}'..='\u{24f}' if ch.is_alphabetic() => Some(Script::Latin),
+        '\u{3040}'..='\u{30ff}'
+        | '\u{31f0}'..='\u{31ff}'
+        | '\u{ff66}'..='\u{ff9d}'
+        | '\u{3400}'..='\u{4dbf}'
+        | '\u{4e00}'..='\u{9fff}'
+        | '\u{f900}'..='\u{faff}'
+        | '\u{20000}'..='\u{2fa1f}' => Some(Script::Cjk),
+        // Devanagari without the danda punctuation and digits.
+        '\u{900}'..='\u{963}' | '\u{970}'..='\u{97f}' => Some(Script::Devanagari),
+        _ => None,
+    }
+}
+
+fn is_kana(ch: char) -> bool {
+    matches!(ch, '\u{3040}'..='\u{30ff}' | '\u{31f0}'..='\u{31ff}' | '\u{ff66}'..='\u{ff9d}')
+}
+
+/// Language of letters in `script` for a voice speaking `lang`, or `None`
+/// when the voice language is written in that script.
+fn script_lang(script: Script, has_kana: bool, lang: &str) -> Option<&'static str> {
+    let voice = base_lang(lang);
+    match script {
+        Script::Latin => matches!(voice, "ja" | "cmn" | "zh" | "hi").then_some("en-us"),
+        Script::Cjk => match voice {
+            "ja" => None,
+            "cmn" | "zh" if !has_kana => None,
+            _ if has_kana => Some("ja"),
+            _ => Some("cmn"),
+        },
+        Script::Devanagari => (voice != "hi").then_some("hi"),
+    }
+}
+
+/// Append the runs of untagged `span`, split by script.
+fn script_runs(
+    text: &str,
+    span: Range<usize>,
+    lang: &str,
+    inline: &[Range<usize>],
+    runs: &mut Vec<LanguageRun>,
+) {
+    if span.is_empty() {
+        return;
+    }
+
+    // Letters of one script, with the neutral characters between them.
+    let mut groups: Vec<(Range<usize>, Script, bool)> = Vec::new();
+    for (offset, ch) in text[span.clone()].char_indices() {
+        let idx = span.start + offset;
+        let Some(script) = script(ch) else {
+            continue;
+        };
+        // Inline pronunciations are neutral so runs never cut through them.
+        if inline.iter().any(|inline| inline.contains(&idx)) {
+            continue;
+        }
+        let end = idx + ch.len_utf8();
+        match groups.last_mut() {
+            Some((group, last, has_kana)) if *last == script => {
+                group.end = end;
+                *has_kana |= is_kana(ch);
+            }
+            _ => groups.push((idx..end, script, is_kana(ch))),
+        }
+    }
+
+    let mut push = |run: LanguageRun| match runs.last_mut() {
+        Some(last) if last.lang == run.lang && last.span.end == run.span.start => {
+            last.span.end = run.span.end;
+        }
+        _ => runs.push(run),
+    };
+    let mut pos = span.start;
+    for (group, script, has_kana) in groups {
+        push(LanguageRun {
+            span: pos..group.start,
+            lang: None,
+        });
+        push(LanguageRun {
+            span: group.clone(),
+            lang: script_lang(script, has_kana, lang),
+        });
+        pos = group.end;
+    }
+    push(LanguageRun {
+        span: pos..span.end,
+        lang: None,
+    });
+    runs.retain(|run| !run.span.is_empty());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_language, intern_language, language_runs};
+    use crate::engines::kokoro::model::KokoroError;
+
+    #[test]
+    fn interns_language_codes() {
+        assert_eq!(intern_language("pt-br").unwrap(), "pt-br");
+        assert_eq!(intern_language(" KO ").unwrap(), "ko");
+        assert!(matches!(
+            intern_language("xx-klingon"),
+            Err(KokoroError::UnknownLanguage(code)) if code == "xx-klingon"
+        ));
+    }
+
+    #[test]
+    fn detects_kokoro_languages() {
+        let cases = [
+            ("The weather is nice, and I want to go for a walk.", "en-us"),
+            ("¿Dónde está la biblioteca?", "es"),
+            ("Je ne sais pas ce que tu veux dire.", "fr"),
+            ("Non so che cosa vuoi dire, ma è bello.", "it"),
+            ("Eu não sei o que você quer dizer.", "pt-br"),
+            ("今日はいい天気ですね。", "ja"),
+            ("今天天气很好。", "cmn"),
+            ("नमस्ते, आप कैसे हैं?", "hi"),
+        ];
+        for (text, lang) in cases {
+            assert_eq!(detect_language(text), Some(lang), "{text}");
+        }
+        assert_eq!(detect_language("123 + 456 = ?"), None);
+    }
+
+    fn runs<'a>(text: &'a str, lang: &str) -> Vec<(&'a str, Option<&'static str>)> {
+        language_runs(text, lang)
+            .into_iter()
+            .map(|run| (&text[run.span], run.lang))
+            .collect()
+    }
+
+    #[test]
+    fn splits_runs_by_tag_and_script() {
+        assert_eq!(
+            runs("Compra el [en]Apple Watch[/en] hoy.", "es"),
+            [
+                ("Compra el ", None),
+                ("Apple Watch", Some("en-us")),
+                (" hoy.", None)
+            ]
+        );
+        assert_eq!(
+            runs("新しいiPhone 15を買いました。", "ja"),
+            [
+                ("新しい", None),
+                ("iPhone", Some("en-us")),
+                (" 15を買いました。", None)
+            ]
+        );
+        assert_eq!(
+            runs("I love 寿司 and ラーメン.", "en-us"),
+            [
+                ("I love ", None),
+                ("寿司", Some("cmn")),
+                (" and ", None),
+                ("ラーメン", Some("ja")),
+                (".", None)
+            ]
+        );
+        // Tags in the voice language and unknown tags change nothing.
+        assert_eq!(
+            runs("Say [en]hi[/en] [xx]there[/xx]", "en-gb"),
+            [("Say ", None), ("hi", None), (" [xx]there[/xx]", None)]
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+
+use super::model::KokoroError;
+use super::phonemizer::TextPart;
+
+/// User pronunciations, applied before text reaches the phonemizer.
+///
+/// Maps words to IPA in the model's alphabet (e.g. `"kˈOkəɹO"`). Matching
+/// words are spoken from their IPA; the rest of the sentence is phonemized
+/// as usual. Entries can apply to all languages or to one language code, in
+/// which case `"en"` also covers `"en-us"` and `"en-gb"`. The most specific
+/// language wins.
+///
+/// Matching ignores case unless the lexicon is
+/// [case-sensitive](Self::with_case_sensitive) or the entry was added as
+/// case-sensitive. Punctuation around a word (quotes, brackets, commas) does
+/// not prevent a match.
+///
+/// # File format
+///
+/// ```text
+/// # Comments start with '#'. Entries before any section apply to all languages.
+/// Kokoro   kˈOkəɹO
+///
+/// [en-us]
+/// GIF      ɡˈɪf
+/// # A leading '=' makes the entry case-sensitive.
+/// =NeXT    nˈɛkst
+///
+/// [fr]
+/// Kokoro   kɔkɔʁo
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Lexicon {
+    /// Entries keyed by language code; `""` holds entries for all languages.
+    languages: HashMap<String, LanguageEntries>,
+    case_sensitive: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct LanguageEntries {
+    /// Entries that only match with identical case.
+    exact: HashMap<String, String>,
+    /// Entries keyed by lowercase word.
+    folded: HashMap<String, String>,
+}
+
+impl Lexicon {
+    /// Create an empty, case-insensitive lexicon.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make entries added from now on (with [`insert`](Self::insert) or
+    /// [`parse`](Self::parse)) match only with identical case.
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Read a lexicon file in the format described above.
+    pub fn load(path: &Path) -> Result<Self, KokoroError> {
+        let mut lexicon = Self::new();
+        lexicon.load_file(path)?;
+        Ok(lexicon)
+    }
+
+    /// Add the entries of a lexicon file to this lexicon.
+    ///
+    /// Returns the number of entries read.
+    pub fn load_file(&mut self, path: &Path) -> Result<usize, KokoroError> {
+        let content = std::fs::read_to_string(path)?;
+        let count = self
+            .parse(&content)
+            .map_err(|e| KokoroError::Lexicon(format!("{}: {e}", path.display())))?;
+        log::info!("Loaded {count} lexicon entries from {}", path.display());
+        Ok(count)
+    }
+
+    /// Add entries from lexicon file contents. Returns the number of entries
+    /// read, or a description of the first malformed line.
+    pub fn parse(&mut self, content: &str) -> Result<usize, String> {
+        let mut lang: Option<String> = None;
+        let mut count = 0;
+        for (idx, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[') {
+                let section = section
+                    .strip_suffix(']')
+                    .ok_or_else(|| format!("line {}: unterminated section header", idx + 1))?;
+                lang = Some(section.trim().to_string());
+                continue;
+            }
+
+            let (word, ipa) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("line {}: expected a word followed by IPA", idx + 1))?;
+            match word.strip_prefix('=') {
+                Some(word) => self.insert_case_sensitive(lang.as_deref(), word, ipa.trim()),
+                None => self.insert(lang.as_deref(), word, ipa.trim()),
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Add a pronunciation, for one language code or for all languages when
+    /// `lang` is `None`. Replaces an existing entry for the same word.
+    pub fn insert(&mut self, lang: Option<&str>, word: &str, ipa: &str) {
+        if self.case_sensitive {
+            self.insert_case_sensitive(lang, word, ipa);
+        } else {
+            self.entries_mut(lang)
+                .folded
+                .insert(word.to_lowercase(), ipa.to_string());
+        }
+    }
+
+    /// Add a pronunciation that only matches `word` with identical case.
+    pub fn insert_case_sensitive(&mut self, lang: Option<&str>, word: &str, ipa: &str) {
+        self.entries_mut(lang)
+            .exact
+            .insert(word.to_string(), ipa.to_string());
+    }
+
+    /// Merge the entries of `other` into this lexicon, replacing duplicates.
+    pub fn extend(&mut self, other: Lexicon) {
+        for (lang, entries) in other.languages {
+            let target = self.languages.entry(lang).or_default();
+            target.exact.extend(entries.exact);
+            target.folded.extend(entries.folded);
+        }
+    }
+
+    /// Number of entries across all languages.
+    pub fn len(&self) -> usize {
+        self.languages
+            .values()
+            .map(|entries| entries.exact.len() + entries.folded.len())
+            .sum()
+    }
+
+    /// Whether the lexicon has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// IPA for `word` in language `lang`, if the lexicon has an entry.
+    pub fn lookup(&self, word: &str, lang: &str) -> Option<&str> {
+        let lang = lang.to_ascii_lowercase();
+        let mut folded = None;
+        for key in lang_fallbacks(&lang) {
+            let Some(entries) = self.languages.get(key) else {
+                continue;
+            };
+            if let Some(ipa) = entries.exact.get(word) {
+                return Some(ipa);
+            }
+            if !entries.folded.is_empty() {
+                let lower = folded.get_or_insert_with(|| word.to_lowercase());
+                if let Some(ipa) = entries.folded.get(lower.as_str()) {
+                    return Some(ipa);
+                }
+            }
+        }
+        None
+    }
+
+    fn entries_mut(&mut self, lang: Option<&str>) -> &mut LanguageEntries {
+        let key = lang.unwrap_or_default().to_ascii_lowercase();
+        self.languages.entry(key).or_default()
+    }
+
+    /// Replace words with lexicon entries by IPA parts.
+    ///
+    /// Text parts are split around matched words; spans keep referring to
+    /// `text`. Words of parts rewritten by normalization (`"$5"` read as
+    /// `"five dollars"`) have no source position of their own and keep the
+    /// span of their part.
+    pub(crate) fn apply(
+        &self,
+        text: &str,
+        parts: Vec<(TextPart, Range<usize>)>,
+        lang: &str,
+    ) -> Vec<(TextPart, Range<usize>)> {
+        if self.is_empty() {
+            return parts;
+        }
+
+        let mut out = Vec::with_capacity(parts.len());
+        for (part, span) in parts {
+            let (string, foreign) = match &part {
+                TextPart::Text(string) => (string.as_str(), None),
+                TextPart::Foreign(string, part_lang) => (string.as_str(), Some(*part_lang)),
+                TextPart::Punct(_) | TextPart::Ipa(_) => {
+                    out.push((part, span));
+                    continue;
+                }
+            };
+
+            let source = &text[span.clone()];
+            let rewritten = !string.split_whitespace().eq(source.split_whitespace());
+            let words: Vec<(&str, Range<usize>)> = if !rewritten {
+                source
+                    .split_whitespace()
+                    .map(|word| {
+                        let start = word.as_ptr() as usize - text.as_ptr() as usize;
+                        (word, start..start + word.len())
+                    })
+                    .collect()
+            } else {
+                string
+                    .split_whitespace()
+                    .map(|word| (word, span.clone()))
+                    .collect()
+            };
+
+            // Words of the current run that have no entry.
+            let mut pending: Vec<(&str, Range<usize>)> = Vec::new();
+            for (word, word_span) in words {
+                let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+                match self
+                    .lookup(core, foreign.unwrap_or(lang))
+                    .filter(|_| !core.is_empty())
+                {
+                    Some(ipa) => {
+                        flush_words(&mut out, &mut pending, foreign);
+                        let core_span = if rewritten {
+                            word_span
+                        } else {
+                            let start =
+                                word_span.start + (core.as_ptr() as usize - word.as_ptr() as usize);
+                            start..start + core.len()
+                        };
+                        out.push((TextPart::Ipa(ipa.to_string()), core_span));
+                    }
+                    None => pending.push((word, word_span)),
+                }
+            }
+            flush_words(&mut out, &mut pending, foreign);
+        }
+        out
+    }
+}
+
+/// Push unmatched words as one text part with collapsed whitespace, in
+/// language `foreign` if set.
+fn flush_words(
+    out: &mut Vec<(TextPart, Range<usize>)>,
+    words: &mut Vec<(&str, Range<usize>)>,
+    foreign: Option<&'static str>,
+) {
+    if let (Some((_, first)), Some((_, last))) = (words.first(), words.last()) {
+        let span = first.start..last.end;
+        let joined = words
+            .iter()
+            .map(|(word, _)| *word)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let part = match foreign {
+            Some(lang) => TextPart::Foreign(joined, lang),
+            None => TextPart::Text(joined),
+        };
+        out.push((part, span));
+    }
+    words.clear();
+}
+
+/// Language keys to consult for `lang`, most specific first:
+/// `"en-us"` -> `["en-us", "en", ""]`.
+fn lang_fallbacks(lang: &str) -> impl Iterator<Item = &str> {
+    let mut next = Some(lang);
+    std::iter::from_fn(move || {
+        let current = next?;
+        next = if current.is_empty() {
+            None
+        } else {
+            Some(current.rsplit_once('-').map_or("", |(prefix, _)| prefix))
+        };
+        Some(current)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lexicon;
+    use crate::engines::kokoro::normalize::chunked_text_parts;
+    use crate::engines::kokoro::phonemizer::{split_text_parts_with_spans, TextPart};
+
+    #[test]
+    fn parses_sections_and_case_options() {
+        let mut lexicon = Lexicon::new();
+        let count = lexicon
+            .parse("# names\nKokoro kˈOkəɹO\n[en]\nGIF ɡˈɪf\n=NeXT nˈɛkst\n[fr]\nKokoro kɔkɔʁo\n")
+            .unwrap();
+        assert_eq!(count, 4);
+
+        assert_eq!(lexicon.lookup("kokoro", "en-us"), Some("kˈOkəɹO"));
+        assert_eq!(lexicon.lookup("Kokoro", "fr"), Some("kɔkɔʁo"));
+        assert_eq!(lexicon.lookup("gif", "en-gb"), Some("ɡˈɪf"));
+        assert_eq!(lexicon.lookup("gif", "es"), None);
+        assert_eq!(lexicon.lookup("NeXT", "en-us"), Some("nˈɛkst"));
+        assert_eq!(lexicon.lookup("next", "en-us"), None);
+        assert!(lexicon.parse("[en-us\n").is_err());
+
+        let mut strict = Lexicon::new().with_case_sensitive(true);
+        strict.insert(None, "Rust", "ɹˈʌst");
+        assert_eq!(strict.lookup("rust", "en-us"), None);
+    }
+
+    #[test]
+    fn replaces_matched_words_with_ipa_parts() {
+        let mut lexicon = Lexicon::new();
+        lexicon.insert(Some("en-us"), "Kokoro", "kˈOkəɹO");
+
+        let text = "I like \"Kokoro\" a lot, 'Kokoro'.";
+        let parts = lexicon.apply(text, split_text_parts_with_spans(text, "en-us"), "en-us");
+        let parts: Vec<(TextPart, &str)> = parts
+            .into_iter()
+            .map(|(part, span)| (part, &text[span]))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                (TextPart::Text("I like".into()), "I like"),
+                (TextPart::Punct('"'), "\""),
+                (TextPart::Ipa("kˈOkəɹO".into()), "Kokoro"),
+                (TextPart::Punct('"'), "\""),
+                (TextPart::Text("a lot".into()), "a lot"),
+                (TextPart::Punct(','), ","),
+                (TextPart::Ipa("kˈOkəɹO".into()), "Kokoro"),
+                (TextPart::Punct('.'), "."),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_normalized_text_of_rewritten_parts() {
+        let mut lexicon = Lexicon::new();
+        lexicon.insert(None, "kokoro", "kˈOkəɹO");
+        lexicon.insert(None, "dollars", "dˈɑləɹz");
+
+        let text = "Kokoro costs $4.99 on 12/05/2026 at 3:30pm.";
+        let parts = chunked_text_parts(text, "en-us", true, 400).remove(0);
+        let parts: Vec<(TextPart, &str)> = lexicon
+            .apply(text, parts, "en-us")
+            .into_iter()
+            .map(|(part, span)| (part, &text[span]))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                (TextPart::Ipa("kˈOkəɹO".into()), "Kokoro"),
+                (TextPart::Text("costs".into()), "costs"),
+                (TextPart::Text("four".into()), "$4.99"),
+                (TextPart::Ipa("dˈɑləɹz".into()), "$4.99"),
+                (TextPart::Text("and ninety nine cents".into()), "$4.99"),
+                (TextPart::Text("on".into()), "on"),
+                (TextPart::Text("December fifth".into()), "12/05/2026"),
+                (TextPart::Punct(','), "12/05/2026"),
+                (TextPart::Text("twenty twenty six".into()), "12/05/2026"),
+                (TextPart::Text("at".into()), "at"),
+                (TextPart::Text("three thirty".into()), "3:30pm"),
+                (TextPart::Ipa("pˌiˈɛm".into()), "3:30pm"),
+                (TextPart::Punct('.'), "."),
+            ]
+        );
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! This module provides a Kokoro-based synthesis engine that uses the
 //! Kokoro-82M ONNX model for text-to-speech conversion. The engine uses
-//! espeak-ng for phonemization and supports 9 languages.
+//! espeak-ng for phonemization by default (any [`Phonemizer`] can be plugged
+//! in instead) and supports 9 languages.
 //!
 //! # System Requirements
 //!
@@ -11,12 +12,28 @@
 //! - **macOS**: `brew install espeak-ng`
 //! - **Windows**: Download installer from <https://espeak-ng.org/download>
 //!
+//! It is not needed when synthesizing directly from IPA or token IDs.
+//!
+//! With the `espeak-ffi` feature the crate links `libespeak-ng` and
+//! phonemizes in-process (see `EspeakFfiPhonemizer`); only the shared library
+//! and its `espeak-ng-data` directory are needed then, not the binary.
+//!
+//! English voices can also run without espeak-ng through [`EnglishG2p`], a
+//! pure-Rust lexicon and letter-to-sound converter:
+//!
+//! ```rust,no_run
+//! use tts_rs::engines::kokoro::{EnglishG2p, KokoroEngine};
+//!
+//! let engine = KokoroEngine::with_phonemizer(EnglishG2p::new());
+//! ```
+//!
 //! # Model Directory Layout
 //!
 //! ```text
 //! models/kokoro/
 //! ├── kokoro-quant-convinteger.onnx   # 8-bit quantized model (88MB, CPU-optimized)
-//! └── voices-v1.0.bin                  # Voice data archive (.npz format)
+//! ├── voices-v1.0.bin                  # Voice data archive (.npz format)
+//! └── voices.json                      # Optional voice metadata (see `VoiceStore::load_metadata`)
 //! ```
 //!
 //! Download links:
@@ -37,6 +54,25 @@
 //! | `pf_`, `pm_` | Brazilian Portuguese | `pt-br` | Full support |
 //! | `zf_`, `zm_` | Mandarin Chinese | `cmn` | Functional via espeak-ng CJK |
 //!
+//! Voices with other names get their language from
+//! [`KokoroModelParams::voice_languages`] or the `language` of their voice
+//! metadata; voices without one are read as `en-us` with a warning.
+//! [`KokoroInferenceParams::language`] sets the language of a single request:
+//!
+//! ```rust,no_run
+//! use tts_rs::{SynthesisEngine, engines::kokoro::{KokoroEngine, KokoroModelParams}};
+//! use std::path::PathBuf;
+//!
+//! let mut engine = KokoroEngine::new();
+//! let params = KokoroModelParams {
+//!     voice_languages: [("kf".to_string(), "ko".to_string())].into(),
+//!     ..Default::default()
+//! };
+//! engine.load_model_with_params(&PathBuf::from("models/kokoro"), params)?;
+//! assert_eq!(engine.voice_language("kf_custom"), Some("ko"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
 //! # Voice Naming Convention
 //!
 //! Voices follow the pattern `{language_prefix}_{name}`, e.g.:
@@ -45,12 +81,20 @@
 //! - `jf_alpha` — Japanese female "alpha"
 //! - `zf_xiaobei` — Mandarin Chinese female "xiaobei"
 //!
+//! [`KokoroEngine::voice_infos`] describes the loaded voices as
+//! [`VoiceInfo`]s (display name, language, gender, accent, quality grade)
+//! for voice pickers; a `voices.json` sidecar fills in or corrects what the
+//! names imply.
+//!
+//! Voices can be blended by weight, e.g. `"af_heart:0.6+bf_emma:0.4"`; see
+//! [`VoiceMix`].
+//!
 //! # Examples
 //!
 //! ## Basic Usage
 //!
 //! ```rust,no_run
-//! use transcribe_rs::{SynthesisEngine, engines::kokoro::{KokoroEngine, KokoroInferenceParams}};
+//! use tts_rs::{SynthesisEngine, engines::kokoro::{KokoroEngine, KokoroInferenceParams}};
 //! use std::path::PathBuf;
 //!
 //! let mut engine = KokoroEngine::new();
@@ -64,7 +108,7 @@
 //! ## With Custom Voice and Speed
 //!
 //! ```rust,no_run
-//! use transcribe_rs::{SynthesisEngine, engines::kokoro::{KokoroEngine, KokoroInferenceParams}};
+//! use tts_rs::{SynthesisEngine, engines::kokoro::{KokoroEngine, KokoroInferenceParams}};
 //! use std::path::PathBuf;
 //!
 //! let mut engine = KokoroEngine::new();
@@ -79,13 +123,205 @@
 //! engine.synthesize_to_file("Hello from British Emma!", &PathBuf::from("out.wav"), Some(params))?;
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
+//!
+//! ## SSML
+//!
+//! ```rust,no_run
+//! use tts_rs::{SynthesisEngine, engines::kokoro::KokoroEngine};
+//! use std::path::PathBuf;
+//!
+//! let mut engine = KokoroEngine::new();
+//! engine.load_model(&PathBuf::from("models/kokoro"))?;
+//!
+//! let result = engine.synthesize_ssml(
+//!     r#"<speak>Hello!<break time="500ms"/>
+//!        <voice name="bf_emma"><prosody rate="slow">Welcome to Kokoro.</prosody></voice>
+//!     </speak>"#,
+//!     None,
+//! )?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ## Phoneme Input
+//!
+//! Hand-written IPA bypasses espeak-ng:
+//!
+//! ```rust,no_run
+//! use tts_rs::{SynthesisEngine, engines::kokoro::KokoroEngine};
+//! use std::path::PathBuf;
+//!
+//! let mut engine = KokoroEngine::new();
+//! engine.load_model(&PathBuf::from("models/kokoro"))?;
+//!
+//! let result = engine.synthesize_phonemes("kˈOkəɹO ɪz hˈɪɹ.", None)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Single words in plain text can be given a pronunciation inline with
+//! `[display text](/IPA/)`; the rest of the text is phonemized as usual:
+//!
+//! ```rust,no_run
+//! # use tts_rs::{SynthesisEngine, engines::kokoro::KokoroEngine};
+//! # let mut engine = KokoroEngine::new();
+//! let result = engine.synthesize("Welcome to [Kokoro](/kˈOkəɹO/)!", None)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ## Text Normalization
+//!
+//! With [`KokoroInferenceParams::normalize`] set, numbers, currencies, dates,
+//! times, units, ordinals, abbreviations and version strings are spelled out
+//! before phonemization for English voices. Spanish, French, Italian,
+//! Portuguese and Hindi voices follow their language's conventions for
+//! decimal commas, thousands separators, currency placement, day-first dates
+//! and gendered ordinals (`"3,50 €"`, `"17/10/2026"`, `"1ª"`). The rewritten
+//! text can be inspected:
+//!
+//! ```rust
+//! use tts_rs::engines::kokoro::{KokoroEngine, KokoroInferenceParams};
+//!
+//! let engine = KokoroEngine::new();
+//! let params = KokoroInferenceParams {
+//!     normalize: true,
+//!     ..Default::default()
+//! };
+//! let normalized = engine.normalize_text("Dr. Smith paid $4.99.", Some(params))?;
+//! assert_eq!(normalized.as_str(), "Doctor Smith paid four dollars and ninety nine cents.");
+//! # Ok::<(), tts_rs::engines::kokoro::KokoroError>(())
+//! ```
+//!
+//! ## Mixed-Language Text
+//!
+//! Text is phonemized in the voice's language, except for stretches marked
+//! with a language tag (`[en]...[/en]`, also `es`, `fr`, `hi`, `it`, `ja`,
+//! `pt`, `zh`) and letters in a script the voice language is not written
+//! in, such as Latin product names in Japanese text. Those are phonemized in
+//! their own language and still spoken by the chosen voice:
+//!
+//! ```rust,no_run
+//! # use tts_rs::{SynthesisEngine, engines::kokoro::{KokoroEngine, KokoroInferenceParams}};
+//! # let mut engine = KokoroEngine::new();
+//! let params = KokoroInferenceParams {
+//!     voice: "ef_dora".to_string(),
+//!     ..Default::default()
+//! };
+//! let result = engine.synthesize("Compra el [en]Apple Watch[/en] hoy.", Some(params))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! With language detection on, the language of each input picks the voice
+//! when the requested one does not speak it:
+//!
+//! ```rust
+//! use tts_rs::engines::kokoro::{detect_language, KokoroEngine};
+//!
+//! let mut engine = KokoroEngine::new();
+//! engine.set_language_detection(true);
+//! assert_eq!(detect_language("¿Dónde está la estación?"), Some("es"));
+//! ```
+//!
+//! ## Custom Pronunciations
+//!
+//! A [`Lexicon`] fixes the pronunciation of names and jargon while the rest
+//! of the text still goes through the phonemizer:
+//!
+//! ```rust,no_run
+//! use tts_rs::{SynthesisEngine, engines::kokoro::{KokoroEngine, Lexicon}};
+//! use std::path::PathBuf;
+//!
+//! let mut lexicon = Lexicon::new();
+//! lexicon.insert(Some("en"), "Kokoro", "kˈOkəɹO");
+//!
+//! let mut engine = KokoroEngine::new();
+//! engine.set_lexicon(lexicon);
+//! engine.load_model(&PathBuf::from("models/kokoro"))?;
+//!
+//! let result = engine.synthesize("Kokoro is here.", None)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ## Custom Voices
+//!
+//! Voices from standalone `.npy` files, extra `.npz` archives or style
+//! vectors computed in memory can be added next to the official ones once
+//! the model is loaded:
+//!
+//! ```rust,no_run
+//! use tts_rs::{SynthesisEngine, engines::kokoro::{KokoroEngine, KokoroInferenceParams}};
+//! use std::path::{Path, PathBuf};
+//!
+//! let mut engine = KokoroEngine::new();
+//! engine.load_model(&PathBuf::from("models/kokoro"))?;
+//! engine.add_voice_file("af_brand", Path::new("voices/af_brand.npy"))?;
+//!
+//! let params = KokoroInferenceParams {
+//!     voice: "af_brand".to_string(),
+//!     ..Default::default()
+//! };
+//! let result = engine.synthesize("Hello from our own voice.", Some(params))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Blends and custom voices can be written back to a `.npz` archive that
+//! loads on other machines and in the Python kokoro-onnx tools:
+//!
+//! ```rust,no_run
+//! # use tts_rs::{SynthesisEngine, engines::kokoro::KokoroEngine};
+//! # use std::path::Path;
+//! # let mut engine = KokoroEngine::new();
+//! engine.add_voice_mix("af_blend", &"af_heart:0.6+bf_emma:0.4".parse()?)?;
+//! engine.save_voices(Path::new("my-voices.npz"), &["af_blend", "af_brand"])?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ## Streaming Synthesis
+//!
+//! Audio is produced sentence by sentence, so playback can start as soon as
+//! the first chunk arrives:
+//!
+//! ```rust,no_run
+//! use tts_rs::{SynthesisEngine, engines::kokoro::KokoroEngine};
+//! use std::path::PathBuf;
+//!
+//! let mut engine = KokoroEngine::new();
+//! engine.load_model(&PathBuf::from("models/kokoro"))?;
+//!
+//! let text = "First sentence. Second sentence.";
+//! for chunk in engine.synthesize_stream(text, None)? {
+//!     let chunk = chunk?;
+//!     println!("{:?}: {} samples", &text[chunk.text_span.clone()], chunk.samples.len());
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
 
+#[cfg(feature = "tokio")]
+pub mod async_engine;
 pub mod engine;
+#[cfg(feature = "espeak-ffi")]
+pub mod espeak_ffi;
+mod espeak_worker;
+pub mod g2p;
+mod language;
+pub mod lexicon;
 pub mod model;
+pub mod normalize;
 pub mod phonemizer;
+pub mod ssml;
+pub mod text_stream;
 pub mod vocab;
 pub mod voices;
 
+#[cfg(feature = "tokio")]
+pub use async_engine::{AsyncKokoroEngine, SynthesisChunkStream};
 pub use engine::{KokoroEngine, KokoroInferenceParams, KokoroModelParams};
-pub use model::KokoroError;
-pub use phonemizer::EspeakConfig;
+#[cfg(feature = "espeak-ffi")]
+pub use espeak_ffi::EspeakFfiPhonemizer;
+pub use g2p::{EnglishAccent, EnglishG2p};
+pub use language::{default_voice, detect_language};
+pub use lexicon::Lexicon;
+pub use model::{KokoroError, KokoroStream};
+pub use normalize::{normalize_text, NormalizedText};
+pub use phonemizer::{EspeakConfig, EspeakPhonemizer, Phonemizer};
+pub use ssml::SsmlDocument;
+pub use text_stream::TextStreamSynthesizer;
+pub use voices::{VoiceGender, VoiceInfo, VoiceMetadata, VoiceMix};
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
 use std::path::Path;
 
 use ndarray::Array2;
@@ -8,12 +9,24 @@ use ort::session::builder::GraphOptimizationLevel;
 use ort::session::Session;
 use ort::value::TensorRef;
 
-use super::phonemizer::{phonemize, voice_lang, EspeakConfig};
+use super::language::{intern_language, language_runs, LanguageRun};
+use super::lexicon::Lexicon;
+use super::normalize::{chunked_text_parts, span_parts};
+use super::phonemizer::{
+    phonemize_ipa, phonemize_parts, split_sentences_for, split_text_chunks, voice_prefix_lang,
+    PhonemizedText, Phonemizer, TextPart,
+};
 use super::voices::VoiceStore;
+use crate::{PhonemeTimestamp, SynthesisChunk, SynthesisResult, WordTimestamp};
 
 /// Maximum number of phoneme tokens per chunk (before padding).
 pub const MAX_PHONEME_LEN: usize = 510;
 
+/// Default maximum length, in characters, of a text chunk synthesized in
+/// one model call. Leaves headroom below `MAX_PHONEME_LEN` for languages
+/// whose IPA runs longer than their spelling.
+pub const DEFAULT_MAX_CHUNK_CHARS: usize = 400;
+
 /// Style vector dimension for Kokoro.
 pub const STYLE_DIM: usize = 256;
 
@@ -40,12 +53,24 @@ pub enum KokoroError {
     PhonemizerFailed(String),
     #[error("Voice '{0}' not found. Call list_voices() to see available voices.")]
     VoiceNotFound(String),
+    #[error("Invalid voice mix: {0}")]
+    VoiceMix(String),
     #[error("Model not loaded. Call load_model() first.")]
     ModelNotLoaded,
     #[error("Invalid config.json: {0}")]
     Config(String),
     #[error("Failed to parse voice file: {0}")]
     VoiceParse(String),
+    #[error("Token id {0} is not in the Kokoro vocabulary")]
+    UnknownToken(i64),
+    #[error("Invalid SSML: {0}")]
+    Ssml(String),
+    #[error("Invalid lexicon: {0}")]
+    Lexicon(String),
+    #[error("Background synthesis task failed: {0}")]
+    Task(String),
+    #[error("Unknown espeak-ng language '{0}'")]
+    UnknownLanguage(String),
 }
 
 /// Internal Kokoro ONNX model state.
@@ -53,10 +78,19 @@ pub struct KokoroModel {
     session: Session,
     voice_store: VoiceStore,
     vocab: HashMap<char, i64>,
+    /// Reverse of `vocab`: the phoneme each token id stands for, also the
+    /// set of valid ids
+    token_chars: HashMap<i64, char>,
     /// Detected input name: "input_ids" or "tokens"
     tokens_input_name: String,
     /// True if the speed input expects int32, false for float32
     speed_is_int32: bool,
+    /// Name of the per-token duration output, if the export provides one
+    duration_output_name: Option<String>,
+    /// User pronunciations applied before phonemization
+    lexicon: Lexicon,
+    /// Configured voice name prefixes and their languages, longest first
+    voice_languages: Vec<(String, &'static str)>,
 }
 
 impl KokoroModel {
@@ -65,6 +99,8 @@ impl KokoroModel {
     /// The directory must contain:
     /// - An `.onnx` file (preferably `kokoro-quant-convinteger.onnx`)
     /// - A `voices-v1.0.bin` voice archive
+    /// - Optionally a `voices.json` voice metadata file (see
+    ///   [`VoiceStore::load_metadata`])
     /// - Optionally a `config.json` for vocabulary (falls back to hardcoded)
     pub fn load(
         model_dir: &Path,
@@ -79,11 +115,13 @@ impl KokoroModel {
         // Detect input names at load time
         let tokens_input_name = detect_tokens_input(&session);
         let speed_is_int32 = detect_speed_type(&session);
+        let duration_output_name = detect_duration_output(&session);
 
         log::info!(
-            "Detected: tokens_input='{}', speed_is_int32={}",
+            "Detected: tokens_input='{}', speed_is_int32={}, duration_output={:?}",
             tokens_input_name,
-            speed_is_int32
+            speed_is_int32,
+            duration_output_name
         );
 
         // Load voices
@@ -97,7 +135,11 @@ impl KokoroModel {
                 ),
             )));
         }
-        let voice_store = VoiceStore::load(&voices_path)?;
+        let mut voice_store = VoiceStore::load(&voices_path)?;
+        let metadata_path = model_dir.join("voices.json");
+        if metadata_path.exists() {
+            voice_store.load_metadata(&metadata_path)?;
+        }
 
         // Load vocabulary
         let config_path = model_dir.join("config.json");
@@ -112,49 +154,298 @@ impl KokoroModel {
         Ok(Self {
             session,
             voice_store,
+            token_chars: vocab.iter().map(|(&ch, &id)| (id, ch)).collect(),
             vocab,
             tokens_input_name,
             speed_is_int32,
+            duration_output_name,
+            lexicon: Lexicon::default(),
+            voice_languages: Vec::new(),
         })
     }
 
     /// Synthesize audio from text using the given voice and speed.
+    ///
+    /// The result includes word timestamps. They come from the model's
+    /// per-token durations when the ONNX export provides them, and are
+    /// estimated by spreading each chunk's audio evenly over its tokens
+    /// otherwise.
+    ///
+    /// Text longer than `DEFAULT_MAX_CHUNK_CHARS` is synthesized in chunks
+    /// cut at sentence, clause or word boundaries (see
+    /// [`split_text_chunks`](super::phonemizer::split_text_chunks)). The
+    /// text is not normalized, as with the default
+    /// [`KokoroInferenceParams::normalize`](super::KokoroInferenceParams::normalize).
     pub fn synthesize_text(
         &mut self,
         text: &str,
         voice_name: &str,
         speed: f32,
         style_idx_override: Option<usize>,
-        espeak: &EspeakConfig,
-    ) -> Result<Vec<f32>, KokoroError> {
-        let lang = voice_lang(voice_name);
-        let ids = phonemize(text, lang, &self.vocab, espeak)?;
+        phonemizer: &mut dyn Phonemizer,
+    ) -> Result<SynthesisResult, KokoroError> {
+        let lang = self.voice_lang(voice_name);
+        let chunks = chunked_text_parts(text, lang, false, DEFAULT_MAX_CHUNK_CHARS);
+        self.synthesize_chunks(
+            text,
+            chunks,
+            voice_name,
+            speed,
+            style_idx_override,
+            phonemizer,
+        )
+    }
+
+    /// Synthesize pre-split text parts whose spans refer to `text`.
+    ///
+    /// Used by front ends (such as SSML) that build parts themselves, e.g. to
+    /// insert caller-supplied IPA.
+    pub(crate) fn synthesize_parts(
+        &mut self,
+        text: &str,
+        parts: Vec<(TextPart, Range<usize>)>,
+        voice_name: &str,
+        speed: f32,
+        style_idx_override: Option<usize>,
+        phonemizer: &mut dyn Phonemizer,
+    ) -> Result<SynthesisResult, KokoroError> {
+        self.synthesize_chunks(
+            text,
+            vec![parts],
+            voice_name,
+            speed,
+            style_idx_override,
+            phonemizer,
+        )
+    }
+
+    /// Synthesize text chunks given as parts whose spans refer to `text`.
+    ///
+    /// Each chunk is phonemized and run through the model on its own; the
+    /// audio is joined with a short crossfade.
+    pub(crate) fn synthesize_chunks(
+        &mut self,
+        text: &str,
+        chunks: Vec<Vec<(TextPart, Range<usize>)>>,
+        voice_name: &str,
+        speed: f32,
+        style_idx_override: Option<usize>,
+        phonemizer: &mut dyn Phonemizer,
+    ) -> Result<SynthesisResult, KokoroError> {
+        let lang = self.voice_lang(voice_name);
+        let mut phonemized = PhonemizedText::default();
+        let mut chunk_ends = Vec::with_capacity(chunks.len());
+        for parts in chunks {
+            let parts = self.lexicon.apply(text, parts, lang);
+            let chunk = phonemize_parts(text, parts, lang, &self.vocab, &mut *phonemizer)?;
+            let offset = phonemized.ids.len();
+            phonemized
+                .words
+                .extend(chunk.words.into_iter().map(|mut word| {
+                    word.tokens = offset + word.tokens.start..offset + word.tokens.end;
+                    word
+                }));
+            phonemized.ids.extend(chunk.ids);
+            if phonemized.ids.len() > offset {
+                chunk_ends.push(phonemized.ids.len());
+            }
+        }
+        let mut result = self.synthesize_phonemized(
+            text,
+            phonemized,
+            &chunk_ends,
+            voice_name,
+            speed,
+            style_idx_override,
+        )?;
+        result.language = Some(lang.to_string());
+        Ok(result)
+    }
+
+    /// Synthesize tokens that are already aligned to the words of `text`,
+    /// one model call per chunk. `chunk_ends` are the token offsets where
+    /// chunks end.
+    fn synthesize_phonemized(
+        &mut self,
+        text: &str,
+        phonemized: PhonemizedText,
+        chunk_ends: &[usize],
+        voice_name: &str,
+        speed: f32,
+        style_idx_override: Option<usize>,
+    ) -> Result<SynthesisResult, KokoroError> {
+        let ids = phonemized.ids;
 
         if ids.is_empty() {
             log::warn!("No phoneme tokens produced for text: {text:?}");
-            return Ok(vec![]);
+            return Ok(SynthesisResult {
+                samples: vec![],
+                sample_rate: SAMPLE_RATE,
+                words: vec![],
+                phonemes: vec![],
+                marks: vec![],
+                language: None,
+            });
+        }
+
+        let word_ends: Vec<usize> = phonemized.words.iter().map(|w| w.tokens.end).collect();
+        let chunks = limit_chunks(chunk_ends, &word_ends);
+        let (combined, token_spans) =
+            self.synthesize_ids(&ids, &chunks, voice_name, speed, style_idx_override)?;
+
+        let phonemes = phoneme_timestamps(&ids, &token_spans, &self.token_chars);
+        let words = phonemized
+            .words
+            .into_iter()
+            .map(|word| {
+                let (start, end) = if word.tokens.is_empty() {
+                    // Words that produced no tokens get a zero-length slot.
+                    let at = match word.tokens.start {
+                        0 => 0,
+                        i => token_spans.get(i - 1).map_or(combined.len(), |s| s.end),
+                    };
+                    (at, at)
+                } else {
+                    (
+                        token_spans[word.tokens.start].start,
+                        token_spans[word.tokens.end - 1].end,
+                    )
+                };
+                WordTimestamp {
+                    text: text[word.text_span.clone()].to_string(),
+                    text_span: word.text_span,
+                    phonemes: word.phonemes,
+                    start_secs: sample_secs(start),
+                    end_secs: sample_secs(end.max(start)),
+                }
+            })
+            .collect();
+
+        Ok(SynthesisResult {
+            samples: combined,
+            sample_rate: SAMPLE_RATE,
+            words,
+            phonemes,
+            marks: vec![],
+            language: None,
+        })
+    }
+
+    /// Synthesize audio straight from phoneme token IDs, bypassing espeak-ng.
+    ///
+    /// Every ID must be a value of the model vocabulary. The result has
+    /// phoneme timestamps but no word timestamps since there is no source
+    /// text to align to.
+    pub fn synthesize_tokens(
+        &mut self,
+        ids: &[i64],
+        voice_name: &str,
+        speed: f32,
+        style_idx_override: Option<usize>,
+    ) -> Result<SynthesisResult, KokoroError> {
+        if let Some(&id) = ids.iter().find(|id| !self.token_chars.contains_key(id)) {
+            return Err(KokoroError::UnknownToken(id));
         }
+        if ids.is_empty() {
+            return Ok(SynthesisResult {
+                samples: vec![],
+                sample_rate: SAMPLE_RATE,
+                words: vec![],
+                phonemes: vec![],
+                marks: vec![],
+                language: None,
+            });
+        }
+        // Without text, cut long sequences after punctuation or a word.
+        let boundaries: Vec<usize> = ids
+            .iter()
+            .enumerate()
+            .filter(|&(_, id)| BOUNDARY_IDS.contains(id))
+            .map(|(i, _)| i + 1)
+            .collect();
+        let chunks = limit_chunks(&[ids.len()], &boundaries);
+        let (samples, token_spans) =
+            self.synthesize_ids(ids, &chunks, voice_name, speed, style_idx_override)?;
+        Ok(SynthesisResult {
+            samples,
+            sample_rate: SAMPLE_RATE,
+            words: vec![],
+            phonemes: phoneme_timestamps(ids, &token_spans, &self.token_chars),
+            marks: vec![],
+            language: None,
+        })
+    }
 
-        // Split into chunks if needed. Keep a stable style index so adjacent chunks
-        // don't change style/prosody based on chunk length.
+    /// Synthesize audio from an IPA string, bypassing espeak-ng.
+    ///
+    /// The IPA is mapped through the vocabulary as-is; characters outside the
+    /// vocabulary are dropped with a warning. Word timestamps are reported per
+    /// whitespace-separated IPA word, with spans indexing into `ipa`.
+    pub fn synthesize_phonemes(
+        &mut self,
+        ipa: &str,
+        voice_name: &str,
+        speed: f32,
+        style_idx_override: Option<usize>,
+    ) -> Result<SynthesisResult, KokoroError> {
+        let unknown: String = ipa
+            .chars()
+            .filter(|ch| !ch.is_whitespace() && !self.vocab.contains_key(ch))
+            .collect();
+        if !unknown.is_empty() {
+            log::warn!("Dropping phonemes missing from the Kokoro vocab: {unknown:?}");
+        }
+
+        let phonemized = phonemize_ipa(ipa, &self.vocab);
+        let chunk_ends = [phonemized.ids.len()];
+        self.synthesize_phonemized(
+            ipa,
+            phonemized,
+            &chunk_ends,
+            voice_name,
+            speed,
+            style_idx_override,
+        )
+    }
+
+    /// Run token IDs through the model, one call per range of `chunks`.
+    ///
+    /// `chunks` must cover `ids` in order, each at most `MAX_PHONEME_LEN`
+    /// long. Returns the combined audio and the sample range covered by each
+    /// token.
+    fn synthesize_ids(
+        &mut self,
+        ids: &[i64],
+        chunks: &[Range<usize>],
+        voice_name: &str,
+        speed: f32,
+        style_idx_override: Option<usize>,
+    ) -> Result<(Vec<f32>, Vec<Range<usize>>), KokoroError> {
+        // Keep a stable style index so adjacent chunks don't change
+        // style/prosody based on chunk length.
         let style_idx = style_idx_override.unwrap_or(ids.len());
         let estimated_samples = ids.len() * 300;
-        let chunks = if ids.len() > MAX_PHONEME_LEN {
-            log::debug!(
-                "Kokoro phoneme sequence exceeded limit ({} > {}), chunking",
-                ids.len(),
-                MAX_PHONEME_LEN
-            );
-            split_chunks(&ids)
-        } else {
-            vec![ids]
-        };
 
         let mut combined = Vec::with_capacity(estimated_samples);
+        // Sample range in `combined` covered by each token.
+        let mut token_spans: Vec<Range<usize>> = Vec::with_capacity(estimated_samples / 300);
 
-        for chunk_ids in chunks.iter() {
+        for chunk in chunks {
+            let chunk_ids = &ids[chunk.clone()];
             let style = self.voice_store.get_style(voice_name, style_idx)?;
-            let audio = self.synthesize_chunk(chunk_ids, &style, speed)?;
+            let (audio, durations) = self.synthesize_chunk(chunk_ids, &style, speed)?;
+
+            let offset = if combined.is_empty() {
+                0
+            } else {
+                combined.len() - CHUNK_CROSSFADE_SAMPLES.min(combined.len()).min(audio.len())
+            };
+            token_spans.extend(
+                chunk_token_spans(chunk_ids.len(), audio.len(), durations.as_deref())
+                    .into_iter()
+                    .map(|span| offset + span.start..offset + span.end),
+            );
+
             if audio.is_empty() {
                 continue;
             }
@@ -166,16 +457,52 @@ impl KokoroModel {
             }
         }
 
-        Ok(combined)
+        Ok((combined, token_spans))
+    }
+
+    /// Synthesize text sentence by sentence, yielding audio as it is produced.
+    ///
+    /// Each sentence is phonemized and run through the session only when the
+    /// returned iterator is advanced, so the first chunk is available long
+    /// before the whole text has been processed. Sentences longer than
+    /// `DEFAULT_MAX_CHUNK_CHARS` (see [`KokoroStream::with_max_chunk_chars`])
+    /// are cut at clause or word boundaries and yield one chunk per piece.
+    pub fn synthesize_stream<'a>(
+        &'a mut self,
+        text: &'a str,
+        voice_name: &str,
+        speed: f32,
+        style_idx_override: Option<usize>,
+        phonemizer: &'a mut dyn Phonemizer,
+    ) -> KokoroStream<'a> {
+        let lang = self.voice_lang(voice_name);
+        KokoroStream {
+            model: self,
+            text,
+            sentences: split_sentences_for(text, lang).into(),
+            runs: language_runs(text, lang),
+            lang,
+            pending: VecDeque::new(),
+            voice_name: voice_name.to_string(),
+            speed,
+            style_idx_override,
+            phonemizer,
+            normalize: false,
+            max_chunk_chars: DEFAULT_MAX_CHUNK_CHARS,
+            failed: false,
+        }
     }
 
     /// Run ONNX inference on a single chunk of phoneme token IDs.
+    ///
+    /// Returns the waveform and, when the model exposes them, the predicted
+    /// duration of every input position (including the two padding tokens).
     fn synthesize_chunk(
         &mut self,
         tokens: &[i64],
         style: &[f32; STYLE_DIM],
         speed: f32,
-    ) -> Result<Vec<f32>, KokoroError> {
+    ) -> Result<(Vec<f32>, Option<Vec<f32>>), KokoroError> {
         let seq_len = tokens.len() + 2; // +2 for padding tokens
 
         // Build tokens tensor: [[0, t1..tN, 0]]
@@ -205,20 +532,264 @@ impl KokoroModel {
             self.session.run(inputs)?
         };
 
-        // Extract first output as waveform
+        // Extract first non-duration output as waveform
+        let duration_name = self.duration_output_name.as_deref();
         let first_output = output
             .iter()
-            .next()
+            .find(|(name, _)| Some(*name) != duration_name)
             .ok_or_else(|| KokoroError::Ort(ort::Error::new("No output from model")))?;
         let waveform = first_output.1.try_extract_array::<f32>()?;
+        let samples = waveform.as_slice().unwrap_or(&[]).to_vec();
 
-        Ok(waveform.as_slice().unwrap_or(&[]).to_vec())
+        let durations = duration_name
+            .and_then(|name| output.get(name))
+            .and_then(|value| {
+                if let Ok(array) = value.try_extract_array::<i64>() {
+                    Some(array.iter().map(|&d| d as f32).collect::<Vec<_>>())
+                } else if let Ok(array) = value.try_extract_array::<f32>() {
+                    Some(array.iter().copied().collect())
+                } else {
+                    log::warn!("Unsupported element type for duration output");
+                    None
+                }
+            });
+
+        Ok((samples, durations))
     }
 
     /// List all available voice names.
     pub fn list_voices(&self) -> Vec<&str> {
         self.voice_store.list_voices()
     }
+
+    /// The loaded voices.
+    pub fn voices(&self) -> &VoiceStore {
+        &self.voice_store
+    }
+
+    /// The loaded voices, for adding or removing voices.
+    pub fn voices_mut(&mut self) -> &mut VoiceStore {
+        &mut self.voice_store
+    }
+
+    /// User pronunciations applied to text before phonemization.
+    pub fn lexicon(&self) -> &Lexicon {
+        &self.lexicon
+    }
+
+    /// Replace the user pronunciation lexicon.
+    pub fn set_lexicon(&mut self, lexicon: Lexicon) {
+        self.lexicon = lexicon;
+    }
+
+    /// Map voice name prefixes to espeak-ng language codes, replacing any
+    /// mapping set before. The longest matching prefix wins, so a full voice
+    /// name can be given too. See [`voice_language`](Self::voice_language).
+    ///
+    /// Fails without changing the mapping if a code is not an espeak-ng
+    /// language.
+    pub fn set_voice_languages<K, V>(
+        &mut self,
+        languages: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<(), KokoroError>
+    where
+        K: Into<String>,
+        V: AsRef<str>,
+    {
+        let mut voice_languages = languages
+            .into_iter()
+            .map(|(prefix, lang)| Ok((prefix.into(), intern_language(lang.as_ref())?)))
+            .collect::<Result<Vec<_>, KokoroError>>()?;
+        voice_languages.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+        self.voice_languages = voice_languages;
+        Ok(())
+    }
+
+    /// The espeak-ng language of a voice, or of the first voice of a blend.
+    ///
+    /// Looks at the prefixes set with
+    /// [`set_voice_languages`](Self::set_voice_languages) first, then at the
+    /// `language` of the voice's metadata, then at the prefixes of the
+    /// official voices ([`DEFAULT_VOICE_LANGUAGES`](super::phonemizer::DEFAULT_VOICE_LANGUAGES)).
+    /// Metadata naming a language espeak-ng does not have is skipped. Returns
+    /// `None` if none of them knows the voice.
+    pub fn voice_language(&self, voice: &str) -> Option<&'static str> {
+        let voice = voice.split(['+', ':']).next().unwrap_or(voice).trim();
+        self.voice_languages
+            .iter()
+            .find(|(prefix, _)| voice.starts_with(prefix.as_str()))
+            .map(|&(_, lang)| lang)
+            .or_else(|| {
+                self.voice_store
+                    .metadata(voice)
+                    .and_then(|metadata| metadata.language.as_deref())
+                    .and_then(|lang| intern_language(lang).ok())
+            })
+            .or_else(|| voice_prefix_lang(voice))
+    }
+
+    /// [`voice_language`](Self::voice_language), falling back to `"en-us"`.
+    pub(crate) fn voice_lang(&self, voice: &str) -> &'static str {
+        self.voice_language(voice).unwrap_or("en-us")
+    }
+}
+
+/// Incremental synthesis iterator returned by [`KokoroModel::synthesize_stream`].
+///
+/// Yields one [`SynthesisChunk`] per synthesized chunk, in text order. Stops
+/// after the first error.
+pub struct KokoroStream<'a> {
+    model: &'a mut KokoroModel,
+    text: &'a str,
+    sentences: VecDeque<Range<usize>>,
+    /// Language runs of the whole text.
+    runs: Vec<LanguageRun>,
+    /// Language of the text, by default the voice's
+    lang: &'static str,
+    /// Phoneme chunks of the current sentence not yet synthesized.
+    pending: VecDeque<PendingChunk>,
+    voice_name: String,
+    speed: f32,
+    style_idx_override: Option<usize>,
+    phonemizer: &'a mut dyn Phonemizer,
+    /// Normalize each sentence before phonemizing it
+    normalize: bool,
+    /// Longest text chunk, in characters, synthesized in one call
+    max_chunk_chars: usize,
+    failed: bool,
+}
+
+struct PendingChunk {
+    ids: Vec<i64>,
+    style_idx: usize,
+    span: Range<usize>,
+}
+
+impl KokoroStream<'_> {
+    /// Normalize numbers, dates, abbreviations etc. in each sentence before
+    /// phonemizing it (see [`normalize_text`](super::normalize::normalize_text)).
+    /// Off by default.
+    pub fn with_normalization(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Phonemize the text as language `lang` (an espeak-ng code) instead of
+    /// the voice's language.
+    pub fn with_language(mut self, lang: &'static str) -> Self {
+        self.lang = lang;
+        self.sentences = split_sentences_for(self.text, lang).into();
+        self.runs = language_runs(self.text, lang);
+        self
+    }
+
+    /// Cut sentences longer than `max_chars` characters into several chunks
+    /// at clause or word boundaries. Default: `DEFAULT_MAX_CHUNK_CHARS`.
+    pub fn with_max_chunk_chars(mut self, max_chars: usize) -> Self {
+        self.max_chunk_chars = max_chars;
+        self
+    }
+
+    /// Phonemize sentences until one produces tokens, queueing its chunks.
+    fn queue_next_sentence(&mut self) -> Result<bool, KokoroError> {
+        while let Some(span) = self.sentences.pop_front() {
+            let lang = self.lang;
+            let sentence = &self.text[span.clone()];
+            let mut chunks = Vec::new();
+            for chunk in split_text_chunks(sentence, lang, self.max_chunk_chars) {
+                let chunk = span.start + chunk.start..span.start + chunk.end;
+                let parts = span_parts(self.text, chunk, &self.runs, lang, self.normalize);
+                let parts = self.model.lexicon.apply(self.text, parts, lang);
+                let phonemized = phonemize_parts(
+                    self.text,
+                    parts,
+                    lang,
+                    &self.model.vocab,
+                    &mut *self.phonemizer,
+                )?;
+                let word_ends: Vec<usize> = phonemized.words.iter().map(|w| w.tokens.end).collect();
+                for tokens in limit_chunks(&[phonemized.ids.len()], &word_ends) {
+                    let mut words = phonemized
+                        .words
+                        .iter()
+                        .filter(|word| tokens.contains(&word.tokens.start));
+                    let text_span = match words.next() {
+                        Some(first) => {
+                            first.text_span.start..words.next_back().unwrap_or(first).text_span.end
+                        }
+                        None => span.clone(),
+                    };
+                    chunks.push((phonemized.ids[tokens].to_vec(), text_span));
+                }
+            }
+            chunks.retain(|(ids, _)| !ids.is_empty());
+            if chunks.is_empty() {
+                log::debug!("No phoneme tokens for sentence {sentence:?}");
+                continue;
+            }
+
+            // One style index for the whole sentence, as if it were one chunk.
+            let style_idx = self
+                .style_idx_override
+                .unwrap_or_else(|| chunks.iter().map(|(ids, _)| ids.len()).sum());
+            self.pending
+                .extend(chunks.into_iter().map(|(ids, span)| PendingChunk {
+                    ids,
+                    style_idx,
+                    span,
+                }));
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn next_chunk(&mut self) -> Result<Option<SynthesisChunk>, KokoroError> {
+        loop {
+            if self.pending.is_empty() && !self.queue_next_sentence()? {
+                return Ok(None);
+            }
+            let Some(chunk) = self.pending.pop_front() else {
+                continue;
+            };
+
+            let style = self
+                .model
+                .voice_store
+                .get_style(&self.voice_name, chunk.style_idx)?;
+            let (samples, durations) = self
+                .model
+                .synthesize_chunk(&chunk.ids, &style, self.speed)?;
+            if samples.is_empty() {
+                continue;
+            }
+
+            let token_spans =
+                chunk_token_spans(chunk.ids.len(), samples.len(), durations.as_deref());
+            return Ok(Some(SynthesisChunk {
+                phonemes: phoneme_timestamps(&chunk.ids, &token_spans, &self.model.token_chars),
+                samples,
+                sample_rate: SAMPLE_RATE,
+                text_span: chunk.span,
+            }));
+        }
+    }
+}
+
+impl Iterator for KokoroStream<'_> {
+    type Item = Result<SynthesisChunk, KokoroError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.next_chunk() {
+            Ok(chunk) => chunk.map(Ok),
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
 }
 
 /// Find the ONNX model file in the given directory.
@@ -332,34 +903,106 @@ fn detect_speed_type(session: &Session) -> bool {
     true
 }
 
-/// Split phoneme IDs into chunks of at most `MAX_PHONEME_LEN`, preferring punctuation.
-fn split_chunks(ids: &[i64]) -> Vec<Vec<i64>> {
-    let mut chunks = Vec::new();
-    let mut start = 0;
+fn sample_secs(sample: usize) -> f64 {
+    sample as f64 / SAMPLE_RATE as f64
+}
 
-    while start < ids.len() {
-        let end = (start + MAX_PHONEME_LEN).min(ids.len());
-        if end == ids.len() {
-            chunks.push(ids[start..end].to_vec());
-            break;
-        }
+/// Timestamps of tokens `ids`, given the sample range each one covers.
+fn phoneme_timestamps(
+    ids: &[i64],
+    token_spans: &[Range<usize>],
+    token_chars: &HashMap<i64, char>,
+) -> Vec<PhonemeTimestamp> {
+    ids.iter()
+        .zip(token_spans)
+        .map(|(&id, span)| PhonemeTimestamp {
+            phoneme: token_chars
+                .get(&id)
+                .copied()
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+            token_id: id,
+            start_secs: sample_secs(span.start),
+            end_secs: sample_secs(span.end.max(span.start)),
+        })
+        .collect()
+}
 
-        // Try to find a good split point (last punctuation before `end`).
-        // Punctuation IDs (hardcoded vocab): ';':1 ':':2 ',':3 '.':4 '!':5 '?':6
-        const PUNCT_IDS: &[i64] = &[1, 2, 3, 4, 5, 6];
-        let split = ids[start..end]
-            .iter()
-            .enumerate()
-            .rev()
-            .find(|(_, &id)| PUNCT_IDS.contains(&id))
-            .map(|(i, _)| start + i + 1)
-            .unwrap_or(end);
+/// Detect an output carrying per-token durations, as emitted by some exports.
+fn detect_duration_output(session: &Session) -> Option<String> {
+    session
+        .outputs()
+        .iter()
+        .map(|output| output.name())
+        .find(|name| matches!(*name, "duration" | "durations" | "pred_dur"))
+        .map(str::to_string)
+}
 
-        chunks.push(ids[start..split].to_vec());
-        start = split;
+/// Sample range covered by each token of a chunk within the chunk audio.
+///
+/// `durations` covers the padded sequence (`n_tokens + 2` entries, in model
+/// frames) and is rescaled to the actual audio length. Without durations the
+/// audio is spread evenly over the tokens.
+fn chunk_token_spans(
+    n_tokens: usize,
+    n_samples: usize,
+    durations: Option<&[f32]>,
+) -> Vec<Range<usize>> {
+    if let Some(durations) = durations {
+        let total: f32 = durations.iter().map(|d| d.max(0.0)).sum();
+        if total > 0.0 && durations.len() == n_tokens + 2 {
+            let to_sample =
+                |frames: f32| ((frames * n_samples as f32 / total).round() as usize).min(n_samples);
+            let mut elapsed = durations[0].max(0.0);
+            return durations[1..=n_tokens]
+                .iter()
+                .map(|d| {
+                    let start = to_sample(elapsed);
+                    elapsed += d.max(0.0);
+                    start..to_sample(elapsed)
+                })
+                .collect();
+        }
     }
 
-    chunks
+    (0..n_tokens)
+        .map(|i| i * n_samples / n_tokens..(i + 1) * n_samples / n_tokens)
+        .collect()
+}
+
+/// Punctuation and word separator IDs (hardcoded vocab):
+/// ';':1 ':':2 ',':3 '.':4 '!':5 '?':6 ' ':16
+const BOUNDARY_IDS: &[i64] = &[1, 2, 3, 4, 5, 6, 16];
+
+/// Token ranges of the chunks ending at `chunk_ends`, with chunks longer
+/// than `MAX_PHONEME_LEN` cut at the last of `boundaries` (token offsets,
+/// e.g. word ends) that fits, or at the limit when none does.
+///
+/// Text chunks are sized to stay below the limit, so this only triggers
+/// for text whose phonemes run unusually long.
+fn limit_chunks(chunk_ends: &[usize], boundaries: &[usize]) -> Vec<Range<usize>> {
+    let mut limited = Vec::with_capacity(chunk_ends.len());
+    let mut start = 0;
+    for &end in chunk_ends {
+        while end - start > MAX_PHONEME_LEN {
+            let limit = start + MAX_PHONEME_LEN;
+            log::debug!(
+                "Kokoro phoneme chunk exceeded limit ({} > {}), splitting",
+                end - start,
+                MAX_PHONEME_LEN
+            );
+            let cut = boundaries
+                .iter()
+                .rev()
+                .copied()
+                .find(|&b| start < b && b <= limit)
+                .unwrap_or(limit);
+            limited.push(start..cut);
+            start = cut;
+        }
+        limited.push(start..end);
+        start = end;
+    }
+    limited
 }
 
 fn append_with_crossfade(dst: &mut Vec<f32>, src: &[f32], crossfade_samples: usize) {
@@ -379,3 +1022,63 @@ fn append_with_crossfade(dst: &mut Vec<f32>, src: &[f32], crossfade_samples: usi
 
     dst.extend_from_slice(&src[overlap..]);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{chunk_token_spans, limit_chunks, phoneme_timestamps, MAX_PHONEME_LEN};
+
+    #[test]
+    fn token_spans_follow_predicted_durations() {
+        // Padding, three tokens, padding; 10 frames over 1000 samples.
+        let durations = [2.0, 1.0, 4.0, 1.0, 2.0];
+        let spans = chunk_token_spans(3, 1000, Some(&durations));
+        assert_eq!(spans, vec![200..300, 300..700, 700..800]);
+
+        let chars = HashMap::from([(50, 'h'), (156, 'ə'), (4, '.')]);
+        let phonemes = phoneme_timestamps(&[50, 156, 4], &spans, &chars);
+        let timings: Vec<(char, f64, f64)> = phonemes
+            .iter()
+            .map(|p| {
+                (
+                    p.phoneme,
+                    (p.start_secs * 24000.0).round(),
+                    (p.end_secs * 24000.0).round(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            timings,
+            [
+                ('h', 200.0, 300.0),
+                ('ə', 300.0, 700.0),
+                ('.', 700.0, 800.0)
+            ]
+        );
+        assert_eq!(phonemes[1].token_id, 156);
+    }
+
+    #[test]
+    fn limits_chunks_at_word_boundaries() {
+        let words = [300, 500, 700, 1100];
+        assert_eq!(limit_chunks(&[100, 200], &words), vec![0..100, 100..200]);
+        assert_eq!(
+            limit_chunks(&[1100], &words),
+            vec![0..500, 500..700, 700..1100]
+        );
+        // A single word above the limit is cut at the limit.
+        assert_eq!(
+            limit_chunks(&[600], &[600]),
+            vec![0..MAX_PHONEME_LEN, MAX_PHONEME_LEN..600]
+        );
+    }
+
+    #[test]
+    fn token_spans_fall_back_to_even_split() {
+        assert_eq!(
+            chunk_token_spans(4, 100, None),
+            vec![0..25, 25..50, 50..75, 75..100]
+        );
+    }
+}
@@ -0,0 +1,692 @@
+use super::{next_joined, parse_small, rewrite_tokens, tokenize, NormalizedText, Rewrite, Token};
+use crate::engines::kokoro::g2p::{
+    cardinal_words, date_words, ordinal_words, spell_digits, year_words, MONTHS,
+};
+
+/// English rules, with British spellings and date order when `british` is set.
+pub(super) struct English<'a> {
+    text: &'a str,
+    tokens: Vec<Token<'a>>,
+    british: bool,
+}
+
+impl<'a> English<'a> {
+    pub(super) fn new(text: &'a str, british: bool) -> Self {
+        Self {
+            text,
+            tokens: tokenize(text),
+            british,
+        }
+    }
+
+    pub(super) fn normalize(&self) -> NormalizedText {
+        rewrite_tokens(self.text, &self.tokens, |i| self.rewrite(i))
+    }
+
+    fn rewrite(&self, i: usize) -> Option<Rewrite> {
+        self.abbreviation(i)
+            .or_else(|| self.currency(i))
+            .or_else(|| self.time(i))
+            .or_else(|| self.date(i))
+            .or_else(|| self.unit(i))
+            .or_else(|| self.version(self.tokens[i].core))
+            .or_else(|| self.number(i))
+    }
+
+    /// The token after `i`, if `i` is not followed by punctuation.
+    fn next_joined(&self, i: usize) -> Option<&Token<'a>> {
+        next_joined(&self.tokens, i)
+    }
+
+    fn abbreviation(&self, i: usize) -> Option<Rewrite> {
+        let token = &self.tokens[i];
+        let has_period = token.trail.starts_with('.');
+        let next = self.tokens.get(i + 1);
+        let next_is_name = next.is_some_and(|n| n.core.starts_with(|c: char| c.is_uppercase()));
+        let next_is_number = next.is_some_and(|n| n.core.starts_with(|c: char| c.is_ascii_digit()));
+
+        // Titles before a name, with or without a period ("Dr. Smith", "Mr Smith").
+        let title = match token.core {
+            "Mr" => Some("Mister"),
+            "Mrs" => Some("Missus"),
+            "Ms" => Some("Miz"),
+            "Dr" => Some("Doctor"),
+            "Prof" => Some("Professor"),
+            "St" => Some("Saint"),
+            "Mt" => Some("Mount"),
+            "Gen" => Some("General"),
+            "Capt" => Some("Captain"),
+            "Sgt" => Some("Sergeant"),
+            "Lt" => Some("Lieutenant"),
+            "Gov" => Some("Governor"),
+            "Sen" => Some("Senator"),
+            "Rev" => Some("Reverend"),
+            _ => None,
+        };
+        if let Some(title) = title {
+            if next_is_name && (has_period || token.trail.is_empty()) {
+                return Some(Rewrite {
+                    eats_period: has_period,
+                    ..Rewrite::new(1, title)
+                });
+            }
+        }
+
+        let expansion = match token.core {
+            "St" if has_period => "Street",
+            "Jr" if has_period => "Junior",
+            "Sr" if has_period => "Senior",
+            "No" | "no" | "Nos" | "nos" if has_period && next_is_number => "number",
+            "Vol" | "vol" if has_period && next_is_number => "volume",
+            "Fig" | "fig" if has_period && next_is_number => "figure",
+            "ca" if has_period && next_is_number => "circa",
+            "vs" | "Vs" => "versus",
+            _ => match token.core.to_ascii_lowercase().as_str() {
+                "etc" if has_period => "et cetera",
+                "e.g" if has_period => "for example",
+                "i.e" if has_period => "that is",
+                "approx" if has_period => "approximately",
+                "dept" if has_period => "department",
+                _ => return None,
+            },
+        };
+        // Keep the period when it probably also ends the sentence.
+        let eats_period =
+            has_period && next.is_some_and(|n| !n.core.starts_with(|c: char| c.is_uppercase()));
+        Some(Rewrite {
+            eats_period,
+            ..Rewrite::new(1, expansion)
+        })
+    }
+
+    fn currency(&self, i: usize) -> Option<Rewrite> {
+        let core = self.tokens[i].core;
+        let (symbol, amount) = match core.chars().next()? {
+            c @ ('$' | '£' | '€' | '¥') => (c, &core[c.len_utf8()..]),
+            _ => {
+                let c = core.chars().next_back()?;
+                (c, core.strip_suffix(['$', '£', '€', '¥'])?)
+            }
+        };
+        let (unit, units, minor, minors) = match symbol {
+            '$' => ("dollar", "dollars", "cent", "cents"),
+            '£' => ("pound", "pounds", "penny", "pence"),
+            '€' => ("euro", "euros", "cent", "cents"),
+            _ => ("yen", "yen", "", ""),
+        };
+        let number = Number::parse(amount).filter(|n| !n.negative)?;
+
+        // "$1.5 million" -> "one point five million dollars"
+        if let Some(scale) = self.next_joined(i).map(|n| n.core).filter(|s| is_scale(s)) {
+            return Some(Rewrite::new(
+                2,
+                format!("{} {scale} {units}", number.words()),
+            ));
+        }
+
+        let whole = number.integer_words();
+        let one = number.int == "1";
+        let text = match number.frac {
+            Some(cents) if cents.len() == 2 && !minor.is_empty() => {
+                let cents_value: u64 = cents.parse().ok()?;
+                let major = format!("{whole} {}", if one { unit } else { units });
+                match cents_value {
+                    0 => major,
+                    _ if number.int == "0" => {
+                        format!(
+                            "{} {}",
+                            cardinal_words(cents_value),
+                            plural(cents_value, minor, minors)
+                        )
+                    }
+                    _ => format!(
+                        "{major} and {} {}",
+                        cardinal_words(cents_value),
+                        plural(cents_value, minor, minors)
+                    ),
+                }
+            }
+            Some(_) => format!("{} {units}", number.words()),
+            None => format!("{whole} {}", if one { unit } else { units }),
+        };
+        Some(Rewrite::new(1, text))
+    }
+
+    fn time(&self, i: usize) -> Option<Rewrite> {
+        let core = self.tokens[i].core;
+        let (clock, suffix) = split_meridiem(core);
+        let (hour, minute) = match clock.split_once(':') {
+            Some((h, m)) if m.len() == 2 => (parse_small(h)?, Some(parse_small(m)?)),
+            Some(_) => return None,
+            None => (parse_small(clock)?, None),
+        };
+
+        // A separate "pm" token: "3:30 pm", "3 p.m."
+        let (tokens, suffix) = match suffix {
+            Some(suffix) => (1, Some(suffix)),
+            None => match self.next_joined(i).and_then(|n| meridiem(n.core)) {
+                Some(suffix) => (2, Some(suffix)),
+                None => (1, None),
+            },
+        };
+        if minute.is_none() && suffix.is_none() {
+            return None;
+        }
+        let valid_hour = if suffix.is_some() {
+            (1..=12).contains(&hour)
+        } else {
+            hour <= 23
+        };
+        if !valid_hour || minute.is_some_and(|m| m > 59) {
+            return None;
+        }
+
+        let mut words = cardinal_words(hour);
+        match minute {
+            Some(0) if suffix.is_none() => words.push_str(if (1..=12).contains(&hour) {
+                " o'clock"
+            } else {
+                " hundred"
+            }),
+            Some(0) | None => {}
+            Some(m) if m < 10 => words.push_str(&format!(" oh {}", cardinal_words(m))),
+            Some(m) => words.push_str(&format!(" {}", cardinal_words(m))),
+        }
+        if let Some(suffix) = suffix {
+            words.push(' ');
+            words.push_str(suffix);
+        }
+        // The final period of "p.m." unless it also ends the sentence.
+        let last = &self.tokens[i + tokens - 1];
+        let eats_period = last.core.ends_with(".m")
+            && last.trail.starts_with('.')
+            && self
+                .tokens
+                .get(i + tokens)
+                .is_some_and(|n| !n.core.starts_with(|c: char| c.is_uppercase()));
+        Some(Rewrite {
+            eats_period,
+            ..Rewrite::new(tokens, words)
+        })
+    }
+
+    fn date(&self, i: usize) -> Option<Rewrite> {
+        let core = self.tokens[i].core;
+
+        // Numeric dates: 12/05/2026, 2026-05-12
+        let numeric = if let Some((y, rest)) = core.split_once('-') {
+            let (m, d) = rest.split_once('-')?;
+            (y.len() == 4).then_some((parse_small(m)?, parse_small(d)?, y))
+        } else if core.matches('/').count() == 2 {
+            let mut fields = core.split('/');
+            let (a, b, y) = (fields.next()?, fields.next()?, fields.next()?);
+            if a.len() > 2 || b.len() > 2 || !matches!(y.len(), 2 | 4) {
+                return None;
+            }
+            let (a, b) = (parse_small(a)?, parse_small(b)?);
+            let (month, day) = match (self.british, a > 12, b > 12) {
+                (_, true, false) => (b, a),
+                (_, false, true) => (a, b),
+                (true, _, _) => (b, a),
+                (false, _, _) => (a, b),
+            };
+            Some((month, day, y))
+        } else {
+            None
+        };
+        if let Some((month, day, year)) = numeric {
+            let text = date_words(month, Some(day), Some(year), self.british)?;
+            return Some(Rewrite::new(1, text));
+        }
+
+        // "May 5", "Jan. 5th"
+        if let Some(month) = month_name(core) {
+            let has_period = self.tokens[i].trail == ".";
+            let next = self.tokens.get(i + 1)?;
+            if !(self.tokens[i].trail.is_empty() || has_period) || next.protected {
+                return None;
+            }
+            let day = day_of_month(next.core)?;
+            return Some(Rewrite::new(
+                2,
+                format!("{month} {}", ordinal_words(&cardinal_words(day))),
+            ));
+        }
+        // "5 May"
+        let day = day_of_month(core)?;
+        let month = self.next_joined(i).and_then(|n| month_name(n.core))?;
+        Some(Rewrite::new(
+            2,
+            format!("the {} of {month}", ordinal_words(&cardinal_words(day))),
+        ))
+    }
+
+    fn unit(&self, i: usize) -> Option<Rewrite> {
+        let core = self.tokens[i].core;
+
+        // Standalone compound units: "km/h", "mph"
+        if let Some(unit) = UNITS
+            .iter()
+            .find(|u| u.symbol == core && (u.symbol.contains('/') || u.symbol == "mph"))
+        {
+            return Some(Rewrite::new(1, self.localize_unit(unit.plural)));
+        }
+
+        let split = core
+            .char_indices()
+            .find(|&(idx, c)| {
+                !(c.is_ascii_digit()
+                    || c == ','
+                    || c == '.'
+                    || (idx == 0 && (c == '-' || c == '−')))
+            })
+            .map_or(core.len(), |(idx, _)| idx);
+        let number = Number::parse(&core[..split])?;
+
+        let (tokens, symbol) = if split < core.len() {
+            (1, &core[split..])
+        } else {
+            (2, self.next_joined(i)?.core)
+        };
+        let plural_form = number.int != "1" || number.frac.is_some();
+        let words = if symbol == "%" {
+            "percent".to_string()
+        } else {
+            let unit = UNITS.iter().find(|u| u.symbol == symbol)?;
+            self.localize_unit(if plural_form {
+                unit.plural
+            } else {
+                unit.singular
+            })
+        };
+        Some(Rewrite::new(tokens, format!("{} {words}", number.words())))
+    }
+
+    fn localize_unit(&self, words: &str) -> String {
+        if self.british {
+            words.replace("meter", "metre").replace("liter", "litre")
+        } else {
+            words.to_string()
+        }
+    }
+
+    /// Version strings: "v2.0.1", "2.0.1"
+    fn version(&self, core: &str) -> Option<Rewrite> {
+        let (prefix, rest) = match core.strip_prefix('v') {
+            Some(rest) => ("version ", rest),
+            None => match core.strip_prefix('V').filter(|rest| rest.contains('.')) {
+                Some(rest) => ("version ", rest),
+                None => ("", core),
+            },
+        };
+        let fields: Vec<&str> = rest.split('.').collect();
+        if fields
+            .iter()
+            .any(|f| f.is_empty() || !f.chars().all(|c| c.is_ascii_digit()))
+            || (prefix.is_empty() && fields.len() < 3)
+            || fields.iter().any(|f| f.len() > 6)
+        {
+            return None;
+        }
+        let words: Vec<String> = fields
+            .iter()
+            .map(|f| match f.parse::<u64>() {
+                Ok(0) => "oh".to_string(),
+                Ok(n) if f.len() == 1 || !f.starts_with('0') => cardinal_words(n),
+                _ => spell_digits(f),
+            })
+            .collect();
+        Some(Rewrite::new(
+            1,
+            format!("{prefix}{}", words.join(" point ")),
+        ))
+    }
+
+    fn number(&self, i: usize) -> Option<Rewrite> {
+        let core = self.tokens[i].core;
+
+        if let Some(rest) = core.strip_prefix('#') {
+            let number = Number::parse(rest).filter(|n| n.frac.is_none() && !n.negative)?;
+            return Some(Rewrite::new(1, format!("number {}", number.words())));
+        }
+
+        // Ordinals: 1st, 22nd, 103rd
+        if let Some(digits) = ["st", "nd", "rd", "th", "ST", "ND", "RD", "TH"]
+            .iter()
+            .find_map(|s| core.strip_suffix(s))
+        {
+            let number = Number::parse(digits).filter(|n| n.frac.is_none() && !n.negative)?;
+            return Some(Rewrite::new(1, ordinal_words(&number.integer_words())));
+        }
+
+        // Decades: 1990s
+        if let Some(digits) = core.strip_suffix('s') {
+            let year: u64 = digits.parse().ok().filter(|_| digits.len() == 4)?;
+            let words = year_words(year);
+            let words = match words.strip_suffix('y') {
+                Some(stem) => format!("{stem}ies"),
+                None => format!("{words}s"),
+            };
+            return Some(Rewrite::new(1, words));
+        }
+
+        // Fractions: 1/2, 3/4
+        if let Some((a, b)) = core.split_once('/') {
+            let (a, b) = (parse_small(a)?, parse_small(b)?);
+            if a == 0 || a >= b || b > 10 {
+                return None;
+            }
+            let denominator = match b {
+                2 => plural(a, "half", "halves").to_string(),
+                4 => plural(a, "quarter", "quarters").to_string(),
+                _ => {
+                    let ordinal = ordinal_words(&cardinal_words(b));
+                    if a == 1 {
+                        ordinal
+                    } else {
+                        format!("{ordinal}s")
+                    }
+                }
+            };
+            return Some(Rewrite::new(
+                1,
+                format!("{} {denominator}", cardinal_words(a)),
+            ));
+        }
+
+        // Ranges: 10-20, 1990-2000
+        if let Some((a, b)) = core.split_once(['-', '–']).filter(|(a, _)| !a.is_empty()) {
+            if a.len() > 4 || b.len() > 4 {
+                return None;
+            }
+            let (x, y): (u64, u64) = (a.parse().ok()?, b.parse().ok()?);
+            if x >= y || a.starts_with('0') || b.starts_with('0') {
+                return None;
+            }
+            let words = |n: u64, s: &str| {
+                if s.len() == 4 && is_year(n) {
+                    year_words(n)
+                } else {
+                    cardinal_words(n)
+                }
+            };
+            return Some(Rewrite::new(
+                1,
+                format!("{} to {}", words(x, a), words(y, b)),
+            ));
+        }
+
+        let number = Number::parse(core)?;
+        let text = match core.parse::<u64>() {
+            Ok(year) if core.len() == 4 && is_year(year) => year_words(year),
+            _ => number.words(),
+        };
+        Some(Rewrite::new(1, text))
+    }
+}
+
+/// A decimal number as written: optional sign, digits with optional
+/// thousands separators, optional fraction.
+struct Number<'a> {
+    negative: bool,
+    /// Integer digits without separators.
+    int: String,
+    frac: Option<&'a str>,
+}
+
+impl<'a> Number<'a> {
+    fn parse(s: &'a str) -> Option<Self> {
+        let (negative, s) = match s.strip_prefix(['-', '−']) {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (int, frac) = match s.split_once('.') {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (s, None),
+        };
+        if frac.is_some_and(|f| f.is_empty() || !f.chars().all(|c| c.is_ascii_digit())) {
+            return None;
+        }
+        let groups: Vec<&str> = int.split(',').collect();
+        let valid_groups = groups.len() == 1
+            || (!groups[0].is_empty()
+                && groups[0].len() <= 3
+                && groups[1..].iter().all(|g| g.len() == 3));
+        if int.is_empty()
+            || !valid_groups
+            || !groups.iter().all(|g| g.chars().all(|c| c.is_ascii_digit()))
+        {
+            return None;
+        }
+        Some(Self {
+            negative,
+            int: groups.concat(),
+            frac,
+        })
+    }
+
+    fn integer_words(&self) -> String {
+        // Long numbers and numbers with leading zeros are read digit by digit.
+        match self.int.parse::<u64>() {
+            Ok(n)
+                if self.int.len() <= 15 && (self.int.len() == 1 || !self.int.starts_with('0')) =>
+            {
+                cardinal_words(n)
+            }
+            _ => spell_digits(&self.int),
+        }
+    }
+
+    fn words(&self) -> String {
+        let mut words = String::new();
+        if self.negative {
+            words.push_str("minus ");
+        }
+        words.push_str(&self.integer_words());
+        if let Some(frac) = self.frac {
+            words.push_str(" point ");
+            words.push_str(&spell_digits(frac));
+        }
+        words
+    }
+}
+
+struct Unit {
+    symbol: &'static str,
+    singular: &'static str,
+    plural: &'static str,
+}
+
+const fn unit(symbol: &'static str, singular: &'static str, plural: &'static str) -> Unit {
+    Unit {
+        symbol,
+        singular,
+        plural,
+    }
+}
+
+#[rustfmt::skip]
+const UNITS: &[Unit] = &[
+    unit("km/h", "kilometer per hour", "kilometers per hour"),
+    unit("kph", "kilometer per hour", "kilometers per hour"),
+    unit("mph", "mile per hour", "miles per hour"),
+    unit("m/s", "meter per second", "meters per second"),
+    unit("km", "kilometer", "kilometers"),
+    unit("m", "meter", "meters"),
+    unit("cm", "centimeter", "centimeters"),
+    unit("mm", "millimeter", "millimeters"),
+    unit("mi", "mile", "miles"),
+    unit("ft", "foot", "feet"),
+    unit("kg", "kilogram", "kilograms"),
+    unit("g", "gram", "grams"),
+    unit("mg", "milligram", "milligrams"),
+    unit("lb", "pound", "pounds"),
+    unit("lbs", "pound", "pounds"),
+    unit("oz", "ounce", "ounces"),
+    unit("l", "liter", "liters"),
+    unit("L", "liter", "liters"),
+    unit("ml", "milliliter", "milliliters"),
+    unit("mL", "milliliter", "milliliters"),
+    unit("°C", "degree Celsius", "degrees Celsius"),
+    unit("°F", "degree Fahrenheit", "degrees Fahrenheit"),
+    unit("°", "degree", "degrees"),
+    unit("KB", "kilobyte", "kilobytes"),
+    unit("MB", "megabyte", "megabytes"),
+    unit("GB", "gigabyte", "gigabytes"),
+    unit("TB", "terabyte", "terabytes"),
+    unit("Hz", "hertz", "hertz"),
+    unit("kHz", "kilohertz", "kilohertz"),
+    unit("MHz", "megahertz", "megahertz"),
+    unit("GHz", "gigahertz", "gigahertz"),
+    unit("ms", "millisecond", "milliseconds"),
+    unit("min", "minute", "minutes"),
+    unit("hrs", "hour", "hours"),
+    unit("W", "watt", "watts"),
+    unit("kW", "kilowatt", "kilowatts"),
+    unit("kWh", "kilowatt hour", "kilowatt hours"),
+    unit("V", "volt", "volts"),
+    unit("mAh", "milliamp hour", "milliamp hours"),
+];
+
+/// Full month name for a capitalized month name or abbreviation.
+fn month_name(word: &str) -> Option<&'static str> {
+    if !word.starts_with(|c: char| c.is_ascii_uppercase()) || word.len() < 3 {
+        return None;
+    }
+    let lower = word.to_ascii_lowercase();
+    MONTHS.iter().copied().find(|month| {
+        let month_lower = month.to_ascii_lowercase();
+        month_lower == lower
+            || (lower.len() == 3 && month_lower.starts_with(&lower))
+            || (lower == "sept" && *month == "September")
+    })
+}
+
+/// Day of month written as `5`, `05` or `5th`.
+fn day_of_month(word: &str) -> Option<u64> {
+    let digits = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|s| word.strip_suffix(s))
+        .unwrap_or(word);
+    parse_small(digits).filter(|d| (1..=31).contains(d))
+}
+
+/// `"pm"`, `"p.m"`, `"PM"` etc. as an inline pronunciation.
+fn meridiem(word: &str) -> Option<&'static str> {
+    match word.to_ascii_lowercase().as_str() {
+        "am" | "a.m" | "a.m." => Some("[AM](/ˌAˈɛm/)"),
+        "pm" | "p.m" | "p.m." => Some("[PM](/pˌiˈɛm/)"),
+        _ => None,
+    }
+}
+
+/// Split an attached meridiem off a time: `"3:30pm"` -> `("3:30", PM)`.
+fn split_meridiem(word: &str) -> (&str, Option<&'static str>) {
+    match word.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(idx) if idx > 0 => match meridiem(&word[idx..]) {
+            Some(suffix) => (&word[..idx], Some(suffix)),
+            None => (word, None),
+        },
+        _ => (word, None),
+    }
+}
+
+fn is_scale(word: &str) -> bool {
+    matches!(word, "thousand" | "million" | "billion" | "trillion")
+}
+
+fn is_year(n: u64) -> bool {
+    (1100..=2099).contains(&n)
+}
+
+fn plural<'s>(n: u64, singular: &'s str, plural: &'s str) -> &'s str {
+    if n == 1 {
+        singular
+    } else {
+        plural
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::normalize_text;
+
+    fn us(text: &str) -> String {
+        normalize_text(text, "en-us").into()
+    }
+
+    #[test]
+    fn verbalizes_numbers_currency_and_units() {
+        assert_eq!(
+            us("It costs $4.99."),
+            "It costs four dollars and ninety nine cents."
+        );
+        assert_eq!(
+            us("$1 or $1.5 million"),
+            "one dollar or one point five million dollars"
+        );
+        assert_eq!(
+            us("1,204 and 3.14"),
+            "one thousand two hundred four and three point one four"
+        );
+        assert_eq!(
+            us("the 21st time, 50% off"),
+            "the twenty first time, fifty percent off"
+        );
+        assert_eq!(
+            us("at 80 km/h or 5km"),
+            "at eighty kilometers per hour or five kilometers"
+        );
+        assert_eq!(
+            us("a 1 kg bag, 25°C"),
+            "a one kilogram bag, twenty five degrees Celsius"
+        );
+        assert_eq!(
+            normalize_text("5 km, -3", "en-gb").as_str(),
+            "five kilometres, minus three"
+        );
+        assert_eq!(
+            us("pages 10-20, 1/2 cup"),
+            "pages ten to twenty, one half cup"
+        );
+    }
+
+    #[test]
+    fn verbalizes_dates_times_and_versions() {
+        assert_eq!(us("12/05/2026"), "December fifth, twenty twenty six");
+        assert_eq!(
+            normalize_text("12/05/2026", "en-gb").as_str(),
+            "the twelfth of May twenty twenty six"
+        );
+        assert_eq!(us("On May 5, 1990."), "On May fifth, nineteen ninety.");
+        assert_eq!(us("in the 1990s"), "in the nineteen nineties");
+        assert_eq!(us("at 3:30pm"), "at three thirty [PM](/pˌiˈɛm/)");
+        assert_eq!(us("at 9 a.m. sharp"), "at nine [AM](/ˌAˈɛm/) sharp");
+        assert_eq!(us("by 10:05 or 15:00"), "by ten oh five or fifteen hundred");
+        assert_eq!(
+            us("v2.0.1 and 1.10.3"),
+            "version two point oh point one and one point ten point three"
+        );
+    }
+
+    #[test]
+    fn expands_abbreviations() {
+        assert_eq!(
+            us("Dr. Smith met Mr Jones."),
+            "Doctor Smith met Mister Jones."
+        );
+        assert_eq!(
+            us("Cats, dogs, etc. are pets"),
+            "Cats, dogs, et cetera are pets"
+        );
+        assert_eq!(us("Cats, dogs, etc."), "Cats, dogs, et cetera.");
+        assert_eq!(
+            us("see No. 5 vs. no. 6"),
+            "see number five versus number six"
+        );
+        assert_eq!(
+            us("Leave [v2](/vˈi tˈu/) alone"),
+            "Leave [v2](/vˈi tˈu/) alone"
+        );
+    }
+}
@@ -0,0 +1,716 @@
+use std::borrow::Cow;
+
+use super::numbers::{cardinal, hindi_ordinal_stem, hindi_year, ordinal, Gender};
+use super::{next_joined, parse_small, rewrite_tokens, tokenize, NormalizedText, Rewrite, Token};
+
+/// A language with its own normalization rules besides English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Locale {
+    Spanish,
+    French,
+    Italian,
+    Portuguese,
+    Hindi,
+}
+
+impl Locale {
+    /// The locale for a language code such as `"es"`, `"fr-fr"` or `"pt-br"`.
+    pub(super) fn from_lang(lang: &str) -> Option<Self> {
+        match lang.split(['-', '_']).next()? {
+            "es" => Some(Self::Spanish),
+            "fr" => Some(Self::French),
+            "it" => Some(Self::Italian),
+            "pt" => Some(Self::Portuguese),
+            "hi" => Some(Self::Hindi),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(self) -> &'static str {
+        match self {
+            Self::Spanish => "coma",
+            Self::French => "virgule",
+            Self::Italian => "virgola",
+            Self::Portuguese => "vírgula",
+            Self::Hindi => "दशमलव",
+        }
+    }
+
+    fn minus(self) -> &'static str {
+        match self {
+            Self::Spanish | Self::Portuguese => "menos",
+            Self::French => "moins",
+            Self::Italian => "meno",
+            Self::Hindi => "ऋण",
+        }
+    }
+
+    fn percent(self) -> &'static str {
+        match self {
+            Self::Spanish => "por ciento",
+            Self::French => "pour cent",
+            Self::Italian => "per cento",
+            Self::Portuguese => "por cento",
+            Self::Hindi => "प्रतिशत",
+        }
+    }
+
+    /// Joins the units of an amount: "tres euros con cincuenta céntimos".
+    fn money_and(self) -> &'static str {
+        match self {
+            Self::Spanish => "con",
+            Self::French => "et",
+            Self::Italian | Self::Portuguese => "e",
+            Self::Hindi => "और",
+        }
+    }
+
+    fn months(self) -> &'static [&'static str; 12] {
+        match self {
+            Self::Spanish => &[
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            Self::French => &[
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            Self::Italian => &[
+                "gennaio",
+                "febbraio",
+                "marzo",
+                "aprile",
+                "maggio",
+                "giugno",
+                "luglio",
+                "agosto",
+                "settembre",
+                "ottobre",
+                "novembre",
+                "dicembre",
+            ],
+            Self::Portuguese => &[
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ],
+            Self::Hindi => &[
+                "जनवरी",
+                "फ़रवरी",
+                "मार्च",
+                "अप्रैल",
+                "मई",
+                "जून",
+                "जुलाई",
+                "अगस्त",
+                "सितंबर",
+                "अक्टूबर",
+                "नवंबर",
+                "दिसंबर",
+            ],
+        }
+    }
+
+    /// Currencies in the order their symbols are tried.
+    fn currencies(self) -> &'static [Currency] {
+        match self {
+            Self::Spanish => ES_CURRENCIES,
+            Self::French => FR_CURRENCIES,
+            Self::Italian => IT_CURRENCIES,
+            Self::Portuguese => PT_CURRENCIES,
+            Self::Hindi => HI_CURRENCIES,
+        }
+    }
+
+    /// Whether a count takes the plural: French uses the singular below 2.
+    fn is_plural(self, n: u64) -> bool {
+        match self {
+            Self::French => n >= 2,
+            _ => n != 1,
+        }
+    }
+}
+
+struct Currency {
+    symbols: &'static [&'static str],
+    /// Singular and plural name.
+    names: [&'static str; 2],
+    /// Singular and plural name of the hundredth unit.
+    minor: [&'static str; 2],
+    feminine: bool,
+}
+
+const fn currency(
+    symbols: &'static [&'static str],
+    names: [&'static str; 2],
+    minor: [&'static str; 2],
+    feminine: bool,
+) -> Currency {
+    Currency {
+        symbols,
+        names,
+        minor,
+        feminine,
+    }
+}
+
+const ES_CURRENCIES: &[Currency] = &[
+    currency(
+        &["€", "EUR"],
+        ["euro", "euros"],
+        ["céntimo", "céntimos"],
+        false,
+    ),
+    currency(
+        &["US$", "$", "USD"],
+        ["dólar", "dólares"],
+        ["centavo", "centavos"],
+        false,
+    ),
+    currency(
+        &["£", "GBP"],
+        ["libra", "libras"],
+        ["penique", "peniques"],
+        true,
+    ),
+];
+
+const FR_CURRENCIES: &[Currency] = &[
+    currency(
+        &["€", "EUR"],
+        ["euro", "euros"],
+        ["centime", "centimes"],
+        false,
+    ),
+    currency(
+        &["US$", "$", "USD"],
+        ["dollar", "dollars"],
+        ["cent", "cents"],
+        false,
+    ),
+    currency(&["£", "GBP"], ["livre", "livres"], ["penny", "pence"], true),
+];
+
+const IT_CURRENCIES: &[Currency] = &[
+    currency(
+        &["€", "EUR"],
+        ["euro", "euro"],
+        ["centesimo", "centesimi"],
+        false,
+    ),
+    currency(
+        &["US$", "$", "USD"],
+        ["dollaro", "dollari"],
+        ["centesimo", "centesimi"],
+        false,
+    ),
+    currency(
+        &["£", "GBP"],
+        ["sterlina", "sterline"],
+        ["penny", "pence"],
+        true,
+    ),
+];
+
+const PT_CURRENCIES: &[Currency] = &[
+    currency(
+        &["R$", "BRL"],
+        ["real", "reais"],
+        ["centavo", "centavos"],
+        false,
+    ),
+    currency(
+        &["€", "EUR"],
+        ["euro", "euros"],
+        ["cêntimo", "cêntimos"],
+        false,
+    ),
+    currency(
+        &["US$", "$", "USD"],
+        ["dólar", "dólares"],
+        ["centavo", "centavos"],
+        false,
+    ),
+    currency(&["£", "GBP"], ["libra", "libras"], ["penny", "pence"], true),
+];
+
+const HI_CURRENCIES: &[Currency] = &[
+    currency(
+        &["₹", "Rs", "रु", "INR"],
+        ["रुपया", "रुपये"],
+        ["पैसा", "पैसे"],
+        false,
+    ),
+    currency(&["$", "USD"], ["डॉलर", "डॉलर"], ["सेंट", "सेंट"], false),
+    currency(&["€", "EUR"], ["यूरो", "यूरो"], ["सेंट", "सेंट"], false),
+    currency(&["£", "GBP"], ["पाउंड", "पाउंड"], ["पेंस", "पेंस"], false),
+];
+
+/// Rules for [`Locale`]s: numbers with the locale's decimal and thousands
+/// separators, currency amounts with the symbol before or after, day-first
+/// numeric dates, gendered ordinals and percentages.
+pub(super) struct Localized<'a> {
+    text: &'a str,
+    tokens: Vec<Token<'a>>,
+    locale: Locale,
+}
+
+impl<'a> Localized<'a> {
+    pub(super) fn new(text: &'a str, locale: Locale) -> Self {
+        Self {
+            text,
+            tokens: tokenize(text),
+            locale,
+        }
+    }
+
+    pub(super) fn normalize(&self) -> NormalizedText {
+        rewrite_tokens(self.text, &self.tokens, |i| self.rewrite(i))
+    }
+
+    fn rewrite(&self, i: usize) -> Option<Rewrite> {
+        self.currency(i)
+            .or_else(|| self.date(i))
+            .or_else(|| self.ordinal(i))
+            .or_else(|| self.percent(i))
+            .or_else(|| self.number(i))
+    }
+
+    /// The core of token `i`, with Devanagari digits as ASCII digits.
+    fn core(&self, i: usize) -> Cow<'a, str> {
+        let core = self.tokens[i].core;
+        if !core.contains(|c: char| ('०'..='९').contains(&c)) {
+            return Cow::Borrowed(core);
+        }
+        Cow::Owned(
+            core.chars()
+                .map(|c| match c {
+                    '०'..='९' => char::from(b'0' + (c as u32 - '०' as u32) as u8),
+                    _ => c,
+                })
+                .collect(),
+        )
+    }
+
+    fn currency(&self, i: usize) -> Option<Rewrite> {
+        let core = self.core(i);
+        for currency in self.locale.currencies() {
+            for symbol in currency.symbols {
+                // "€5", "5€"
+                let attached = core
+                    .strip_prefix(symbol)
+                    .or_else(|| core.strip_suffix(symbol))
+                    .filter(|rest| !rest.is_empty());
+                if let Some(number) = attached.and_then(|rest| self.parse_number(rest)) {
+                    return Some(Rewrite::new(1, self.money(currency, &number)?));
+                }
+
+                // "R$ 5", "Rs. 500"
+                if core == *symbol {
+                    let token = &self.tokens[i];
+                    let next = self.tokens.get(i + 1).filter(|next| {
+                        !next.protected
+                            && (token.trail.is_empty()
+                                || (token.trail == "." && symbol.chars().all(char::is_alphabetic)))
+                    });
+                    if next.is_some() {
+                        if let Some((count, number)) = self.read_number(i + 1) {
+                            return Some(Rewrite::new(1 + count, self.money(currency, &number)?));
+                        }
+                    }
+                }
+            }
+        }
+
+        // "3,50 €"
+        let (count, number) = self.read_number(i)?;
+        let symbol = next_joined(&self.tokens, i + count - 1)?.core;
+        let currency = self
+            .locale
+            .currencies()
+            .iter()
+            .find(|currency| currency.symbols.contains(&symbol))?;
+        Some(Rewrite::new(count + 1, self.money(currency, &number)?))
+    }
+
+    fn money(&self, currency: &Currency, number: &LocalNumber) -> Option<String> {
+        let locale = self.locale;
+        let amount: u64 = number.int.parse().ok().filter(|_| number.int.len() <= 15)?;
+        let gender = if currency.feminine {
+            Gender::Feminine
+        } else {
+            Gender::Masculine
+        };
+        let name = currency.names[usize::from(locale.is_plural(amount))];
+
+        let cents = match number.frac.as_deref() {
+            None => None,
+            Some(frac) if frac.len() == 2 => frac.parse::<u64>().ok(),
+            // "1,5 €" is read as a decimal number.
+            Some(_) => {
+                return Some(format!(
+                    "{} {}",
+                    self.number_words(number, None),
+                    currency.names[1]
+                ));
+            }
+        };
+
+        let mut parts = Vec::new();
+        if amount > 0 || cents.is_none_or(|c| c == 0) {
+            let words = cardinal(locale, amount, Some(gender));
+            // A round number of millions takes a preposition: "un millón de euros".
+            let of = match locale {
+                _ if amount == 0 || !amount.is_multiple_of(1_000_000) => "",
+                Locale::French if name.starts_with(['a', 'e', 'i', 'o', 'u']) => "d'",
+                Locale::French | Locale::Spanish | Locale::Portuguese => "de ",
+                Locale::Italian => "di ",
+                Locale::Hindi => "",
+            };
+            parts.push(format!("{words} {of}{name}"));
+        }
+        if let Some(cents) = cents.filter(|&c| c > 0) {
+            let minor = currency.minor[usize::from(locale.is_plural(cents))];
+            parts.push(format!(
+                "{} {minor}",
+                cardinal(locale, cents, Some(Gender::Masculine))
+            ));
+        }
+        let mut words = parts.join(&format!(" {} ", locale.money_and()));
+        if number.negative {
+            words = format!("{} {words}", locale.minus());
+        }
+        Some(words)
+    }
+
+    /// Day-first numeric dates (`17/10/2026`, `17.10.2026`) and ISO dates
+    /// (`2026-10-17`).
+    fn date(&self, i: usize) -> Option<Rewrite> {
+        let core = self.core(i);
+        let separator = core.chars().find(|c| matches!(c, '/' | '-' | '.'))?;
+        let fields: Vec<&str> = core.split(separator).collect();
+        let [a, b, c] = fields[..] else {
+            return None;
+        };
+        let (day, month, year) = if a.len() == 4 && separator == '-' {
+            (parse_small(c)?, parse_small(b)?, a)
+        } else if c.len() == 4 && a.len() <= 2 && b.len() <= 2 {
+            (parse_small(a)?, parse_small(b)?, c)
+        } else {
+            return None;
+        };
+        let year = parse_small(year)?;
+        if !(1..=31).contains(&day) || !(1..=12).contains(&month) {
+            return None;
+        }
+
+        let locale = self.locale;
+        let day = match (locale, day) {
+            (Locale::Spanish, 1) => "primero".to_string(),
+            (Locale::Portuguese, 1) => "primeiro".to_string(),
+            (Locale::French | Locale::Italian, 1) => ordinal(locale, 1, Gender::Masculine),
+            _ => cardinal(locale, day, None),
+        };
+        let month = locale.months()[month as usize - 1];
+        let year = match locale {
+            Locale::Hindi => hindi_year(year),
+            _ => cardinal(locale, year, None),
+        };
+        let text = match locale {
+            Locale::Spanish | Locale::Portuguese => format!("{day} de {month} de {year}"),
+            _ => format!("{day} {month} {year}"),
+        };
+        Some(Rewrite::new(1, text))
+    }
+
+    /// `1º`, `1.ª`, `3.er` (Spanish), `1er`, `2e` (French), `5वाँ` (Hindi).
+    fn ordinal(&self, i: usize) -> Option<Rewrite> {
+        let core = self.core(i);
+        let digits_end = core.find(|c: char| !c.is_ascii_digit())?;
+        let (digits, suffix) = core.split_at(digits_end);
+        let n = parse_small(digits)?;
+        let locale = self.locale;
+
+        let text = match locale {
+            Locale::Hindi => format!("{}{suffix}", hindi_ordinal_stem(n, suffix)?),
+            Locale::French => {
+                let gender = match suffix {
+                    "er" if n == 1 => Gender::Masculine,
+                    "re" | "ère" if n == 1 => Gender::Feminine,
+                    "e" | "ème" | "eme" if n > 1 => Gender::Masculine,
+                    "nd" if n == 2 => return Some(Rewrite::new(1, "second")),
+                    "nde" if n == 2 => return Some(Rewrite::new(1, "seconde")),
+                    _ => return None,
+                };
+                ordinal(locale, n, gender)
+            }
+            _ => {
+                let suffix = suffix.strip_prefix('.').unwrap_or(suffix);
+                match suffix {
+                    "º" | "°" => ordinal(locale, n, Gender::Masculine),
+                    "ª" => ordinal(locale, n, Gender::Feminine),
+                    // "1.er", "3er": "primer", "tercer" before a noun.
+                    "er" if locale == Locale::Spanish => {
+                        let word = ordinal(locale, n, Gender::Masculine);
+                        if !(word.ends_with("primero") || word.ends_with("tercero")) {
+                            return None;
+                        }
+                        word[..word.len() - 1].to_string()
+                    }
+                    _ => return None,
+                }
+            }
+        };
+        Some(Rewrite::new(1, text))
+    }
+
+    fn percent(&self, i: usize) -> Option<Rewrite> {
+        let core = self.core(i);
+        if let Some(number) = core.strip_suffix('%').and_then(|n| self.parse_number(n)) {
+            return Some(Rewrite::new(
+                1,
+                format!(
+                    "{} {}",
+                    self.number_words(&number, None),
+                    self.locale.percent()
+                ),
+            ));
+        }
+        let (count, number) = self.read_number(i)?;
+        if next_joined(&self.tokens, i + count - 1)?.core != "%" {
+            return None;
+        }
+        Some(Rewrite::new(
+            count + 1,
+            format!(
+                "{} {}",
+                self.number_words(&number, None),
+                self.locale.percent()
+            ),
+        ))
+    }
+
+    fn number(&self, i: usize) -> Option<Rewrite> {
+        let (count, number) = self.read_number(i)?;
+        Some(Rewrite::new(count, self.number_words(&number, None)))
+    }
+
+    /// A number starting at token `i`, including thousands groups written
+    /// as separate tokens (`1 000 000`): separated by a no-break space, or by
+    /// any space in French. Returns the number of tokens read.
+    fn read_number(&self, i: usize) -> Option<(usize, LocalNumber)> {
+        let mut joined = self.core(i).into_owned();
+        let mut count = 1;
+        let leading = joined.trim_start_matches(['-', '−']);
+        if (1..=3).contains(&leading.len()) && leading.chars().all(|c| c.is_ascii_digit()) {
+            while !joined.contains(',') {
+                let (token, next) = (&self.tokens[i + count - 1], self.tokens.get(i + count));
+                let Some(next) = next.filter(|next| token.trail.is_empty() && !next.protected)
+                else {
+                    break;
+                };
+                let gap = &self.text[token.span.end..next.span.start];
+                let spaced = matches!(gap, "\u{a0}" | "\u{202f}")
+                    || (gap == " " && self.locale == Locale::French);
+                let group_len = next
+                    .core
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(next.core.len());
+                let is_group =
+                    group_len == 3 && (next.core.len() == 3 || next.core[3..].starts_with(','));
+                if !spaced || !is_group {
+                    break;
+                }
+                joined.push_str(next.core);
+                count += 1;
+            }
+        }
+        Some((count, self.parse_number(&joined)?))
+    }
+
+    /// Parse a number written with the locale's separators: `1.234,5` in
+    /// Spanish, French, Italian and Portuguese, `1,23,456.7` in Hindi.
+    fn parse_number(&self, s: &str) -> Option<LocalNumber> {
+        let (negative, s) = match s.strip_prefix(['-', '−']) {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (decimal, group) = match self.locale {
+            Locale::Hindi => ('.', ','),
+            _ => (',', '.'),
+        };
+        let (int, frac) = match s.rsplit_once(decimal) {
+            Some((int, frac)) => (int, Some(frac)),
+            // "3.5" is still a decimal number when it can't be a thousands group.
+            None => match s.split_once(group) {
+                Some((int, frac)) if frac.len() != 3 && !frac.contains(group) => (int, Some(frac)),
+                _ => (s, None),
+            },
+        };
+        if frac.is_some_and(|f| f.is_empty() || !f.chars().all(|c| c.is_ascii_digit())) {
+            return None;
+        }
+
+        let groups: Vec<&str> = int.split(group).collect();
+        let last = groups.len() - 1;
+        let valid_groups = groups.len() == 1
+            || (!groups[0].is_empty()
+                && groups[0].len() <= 3
+                && groups[1..].iter().enumerate().all(|(idx, g)| {
+                    // Indian grouping: 1,00,00,000
+                    g.len() == 3 || (self.locale == Locale::Hindi && g.len() == 2 && idx + 1 < last)
+                }));
+        if int.is_empty()
+            || !valid_groups
+            || !groups.iter().all(|g| g.chars().all(|c| c.is_ascii_digit()))
+        {
+            return None;
+        }
+        Some(LocalNumber {
+            negative,
+            int: groups.concat(),
+            frac: frac.map(str::to_string),
+        })
+    }
+
+    fn number_words(&self, number: &LocalNumber, gender: Option<Gender>) -> String {
+        let locale = self.locale;
+        let mut words = Vec::new();
+        if number.negative {
+            words.push(locale.minus().to_string());
+        }
+        let int_gender = gender.filter(|_| number.frac.is_none());
+        words.push(digit_words(locale, &number.int, int_gender));
+        if let Some(frac) = &number.frac {
+            words.push(locale.decimal_separator().to_string());
+            // Short fractions are read as a number ("tres coma veinticinco"),
+            // others digit by digit, as in Hindi.
+            if locale != Locale::Hindi && frac.len() <= 2 && !frac.starts_with('0') {
+                words.push(digit_words(locale, frac, None));
+            } else {
+                words.extend(
+                    frac.chars()
+                        .map(|c| digit_words(locale, &c.to_string(), None)),
+                );
+            }
+        }
+        words.join(" ")
+    }
+}
+
+/// A number as written in the locale, without separators.
+struct LocalNumber {
+    negative: bool,
+    int: String,
+    frac: Option<String>,
+}
+
+/// Words for a string of digits; long numbers and numbers with leading
+/// zeros are read digit by digit.
+fn digit_words(locale: Locale, digits: &str, gender: Option<Gender>) -> String {
+    match digits.parse::<u64>() {
+        Ok(n) if digits.len() <= 15 && (digits.len() == 1 || !digits.starts_with('0')) => {
+            cardinal(locale, n, gender)
+        }
+        _ => digits
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .map(|d| cardinal(locale, u64::from(d), None))
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::normalize_text;
+
+    fn normalize(text: &str, lang: &str) -> String {
+        normalize_text(text, lang).into()
+    }
+
+    #[test]
+    fn verbalizes_spanish_and_portuguese() {
+        assert_eq!(
+            normalize("Cuesta 3,50 €.", "es"),
+            "Cuesta tres euros con cincuenta céntimos."
+        );
+        assert_eq!(
+            normalize("1.250.000 personas y 3,25", "es"),
+            "un millón doscientos cincuenta mil personas y tres coma veinticinco"
+        );
+        assert_eq!(
+            normalize("el 17/10/2026, el 21 %", "es"),
+            "el diecisiete de octubre de dos mil veintiséis, el veintiuno por ciento"
+        );
+        assert_eq!(
+            normalize("la 1ª vez, el 3.er puesto, el 22º", "es"),
+            "la primera vez, el tercer puesto, el vigésimo segundo"
+        );
+        assert_eq!(
+            normalize("R$ 1.234,56 e 2026", "pt-br"),
+            "mil duzentos e trinta e quatro reais e cinquenta e seis centavos e dois mil e vinte e seis"
+        );
+        assert_eq!(
+            normalize("2º lugar, 1ª vez, 200 livros", "pt-br"),
+            "segundo lugar, primeira vez, duzentos livros"
+        );
+    }
+
+    #[test]
+    fn verbalizes_french_italian_and_hindi() {
+        assert_eq!(
+            normalize("1 000 000 d'habitants, 3,5 % et 71", "fr-fr"),
+            "un million d'habitants, trois virgule cinq pour cent et soixante et onze"
+        );
+        assert_eq!(
+            normalize("le 1er mai, la 2e fois, 80 €", "fr-fr"),
+            "le premier mai, la deuxième fois, quatre-vingts euros"
+        );
+        assert_eq!(
+            normalize("€ 21,03 e 1.823", "it"),
+            "ventuno euro e tre centesimi e milleottocentoventitré"
+        );
+        assert_eq!(
+            normalize("il 17/10/2026, la 3ª volta", "it"),
+            "il diciassette ottobre duemilaventisei, la terza volta"
+        );
+        assert_eq!(
+            normalize("₹500 और 3.5, 1,00,000", "hi"),
+            "पाँच सौ रुपये और तीन दशमलव पाँच, एक लाख"
+        );
+        assert_eq!(
+            normalize("१७/१०/२०२६ को 5वाँ और 1ला", "hi"),
+            "सत्रह अक्टूबर दो हज़ार छब्बीस को पाँचवाँ और पहला"
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use std::ops::Range;
+
+mod english;
+mod locale;
+mod numbers;
+
+use english::English;
+use locale::{Locale, Localized};
+
+use super::language::{language_runs, LanguageRun};
+use super::phonemizer::{
+    inline_pronunciation_spans, split_text_chunks, split_text_parts_with_spans, TextPart,
+};
+
+/// Text rewritten for reading aloud, with a mapping back to the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedText {
+    text: String,
+    /// `(normalized range, source range)` of every rewritten token, in order.
+    replacements: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl NormalizedText {
+    /// Source text that needs no rewriting.
+    pub(crate) fn unchanged(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            replacements: Vec::new(),
+        }
+    }
+
+    /// The normalized text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Byte range in the source text that produced `span` of the normalized
+    /// text. Spans inside a rewritten token map to the whole source token.
+    pub fn source_span(&self, span: Range<usize>) -> Range<usize> {
+        self.source_pos(span.start, false)..self.source_pos(span.end, true)
+    }
+
+    fn source_pos(&self, pos: usize, is_end: bool) -> usize {
+        let mut shifted = pos;
+        for (normalized, source) in &self.replacements {
+            if pos <= normalized.start {
+                break;
+            }
+            if pos < normalized.end {
+                return if is_end { source.end } else { source.start };
+            }
+            shifted = source.end + (pos - normalized.end);
+        }
+        shifted
+    }
+}
+
+impl From<NormalizedText> for String {
+    fn from(normalized: NormalizedText) -> Self {
+        normalized.text
+    }
+}
+
+/// Rewrite text so that it reads naturally in language `lang`.
+///
+/// For English (`en-us`, `en-gb`) numbers, currencies, dates, times, units,
+/// ordinals, common abbreviations and version strings are spelled out in
+/// words, e.g. `"$4.99"` becomes `"four dollars and ninety nine cents"`.
+/// `AM`/`PM` are written as inline pronunciations (`[PM](/pˌiˈɛm/)`).
+///
+/// Spanish, French, Italian, Portuguese and Hindi get their own rules:
+/// numbers with a decimal comma and `.` or space thousands separators
+/// (`"1.234,5"`, `"1 000"`; Indian grouping `"1,00,000"` in Hindi), currency
+/// amounts with the symbol on either side (`"3,50 €"`, `"R$ 10"`, `"₹500"`),
+/// day-first dates (`"17/10/2026"`), gendered ordinals (`"1ª"`, `"1er"`,
+/// `"5वाँ"`) and percentages. Text in other languages is returned unchanged.
+pub fn normalize_text(text: &str, lang: &str) -> NormalizedText {
+    let lang = lang.to_ascii_lowercase();
+    if lang == "en" || lang.starts_with("en-") {
+        English::new(text, lang.starts_with("en-gb")).normalize()
+    } else if let Some(locale) = Locale::from_lang(&lang) {
+        Localized::new(text, locale).normalize()
+    } else {
+        NormalizedText::unchanged(text)
+    }
+}
+
+/// Split `span` of `text` into parts like `split_text_parts_with_spans`,
+/// normalizing it first when `normalize` is set. Spans always refer to
+/// `text`; each rewritten token becomes its own part so word timestamps
+/// cover the original token.
+///
+/// `runs` are the [`language_runs`] of the whole text. Stretches in another
+/// language than `lang` are normalized and split in their own language and
+/// become [`TextPart::Foreign`] parts.
+pub(crate) fn span_parts(
+    text: &str,
+    span: Range<usize>,
+    runs: &[LanguageRun],
+    lang: &str,
+    normalize: bool,
+) -> Vec<(TextPart, Range<usize>)> {
+    let mut parts = Vec::new();
+    for run in runs {
+        let run_span = run.span.start.max(span.start)..run.span.end.min(span.end);
+        if run_span.is_empty() {
+            continue;
+        }
+        let run_text = &text[run_span.clone()];
+        for (part, part_span) in
+            single_language_parts(run_text, run.lang.unwrap_or(lang), normalize)
+        {
+            let part = match (part, run.lang) {
+                (TextPart::Text(segment), Some(run_lang)) => TextPart::Foreign(segment, run_lang),
+                (part, _) => part,
+            };
+            parts.push((
+                part,
+                run_span.start + part_span.start..run_span.start + part_span.end,
+            ));
+        }
+    }
+    parts
+}
+
+/// Parts of text entirely in language `lang`.
+fn single_language_parts(text: &str, lang: &str, normalize: bool) -> Vec<(TextPart, Range<usize>)> {
+    if !normalize {
+        return split_text_parts_with_spans(text, lang);
+    }
+    let normalized = normalize_text(text, lang);
+    if normalized.replacements.is_empty() {
+        return split_text_parts_with_spans(text, lang);
+    }
+
+    let mut parts = Vec::new();
+    for (part, span) in split_text_parts_with_spans(&normalized.text, lang) {
+        if !matches!(part, TextPart::Text(_)) {
+            parts.push((part, normalized.source_span(span)));
+            continue;
+        }
+        // Cut text parts at rewritten token boundaries.
+        let mut cuts = vec![span.start, span.end];
+        for (replaced, _) in &normalized.replacements {
+            for pos in [replaced.start, replaced.end] {
+                if span.start < pos && pos < span.end {
+                    cuts.push(pos);
+                }
+            }
+        }
+        cuts.sort_unstable();
+        for window in cuts.windows(2) {
+            let piece = &normalized.text[window[0]..window[1]];
+            let trimmed = piece.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let start = window[0] + (piece.len() - piece.trim_start().len());
+            let collapsed = trimmed.split_whitespace().collect::<Vec<_>>().join(" ");
+            parts.push((
+                TextPart::Text(collapsed),
+                normalized.source_span(start..start + trimmed.len()),
+            ));
+        }
+    }
+    parts
+}
+
+/// Cut text into chunks of at most `max_chars` characters (see
+/// [`split_text_chunks`]) and split each into parts like [`span_parts`].
+/// Spans refer to `text`.
+pub(crate) fn chunked_text_parts(
+    text: &str,
+    lang: &str,
+    normalize: bool,
+    max_chars: usize,
+) -> Vec<Vec<(TextPart, Range<usize>)>> {
+    let runs = language_runs(text, lang);
+    split_text_chunks(text, lang, max_chars)
+        .into_iter()
+        .map(|chunk| span_parts(text, chunk, &runs, lang, normalize))
+        .collect()
+}
+
+/// Mark the text parts of `chunks` as being in `lang`, for text whose
+/// language differs from the voice's.
+pub(crate) fn set_language(chunks: &mut [Vec<(TextPart, Range<usize>)>], lang: &'static str) {
+    for (part, _) in chunks.iter_mut().flatten() {
+        if let TextPart::Text(segment) = part {
+            *part = TextPart::Foreign(std::mem::take(segment), lang);
+        }
+    }
+}
+
+/// A whitespace-separated word with surrounding punctuation split off.
+struct Token<'a> {
+    /// The word without leading and trailing punctuation.
+    core: &'a str,
+    /// Byte range of `core` in the source text.
+    span: Range<usize>,
+    /// Punctuation directly after `core`.
+    trail: &'a str,
+    /// Part of an inline pronunciation, which is left alone.
+    protected: bool,
+}
+
+/// A rewrite of one or more consecutive tokens.
+struct Rewrite {
+    /// Number of tokens consumed.
+    tokens: usize,
+    /// Whether a `.` directly after the last token belongs to it (as in
+    /// `"Dr."`) and is replaced too.
+    eats_period: bool,
+    text: String,
+}
+
+impl Rewrite {
+    fn new(tokens: usize, text: impl Into<String>) -> Self {
+        Self {
+            tokens,
+            eats_period: false,
+            text: text.into(),
+        }
+    }
+}
+
+const LEADING_PUNCTUATION: &[char] = &[
+    '"', '\'', '(', '[', '{', '\u{201c}', '\u{2018}', '¿', '¡', '«',
+];
+const TRAILING_PUNCTUATION: &[char] = &[
+    ',', ';', ':', '!', '?', '.', '"', '\'', ')', ']', '}', '\u{201d}', '\u{2019}', '…', '»', '।',
+];
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let protected = inline_pronunciation_spans(text);
+    let mut tokens = Vec::new();
+    for word in text.split_whitespace() {
+        let start = word.as_ptr() as usize - text.as_ptr() as usize;
+        let end = start + word.len();
+        let lead = word.len() - word.trim_start_matches(LEADING_PUNCTUATION).len();
+        let core = word[lead..].trim_end_matches(TRAILING_PUNCTUATION);
+        let span = start + lead..start + lead + core.len();
+        tokens.push(Token {
+            core,
+            span: span.clone(),
+            trail: &text[span.end..end],
+            protected: protected.iter().any(|p| p.start < end && start < p.end),
+        });
+    }
+    tokens
+}
+
+/// Apply `rewrite` to the tokens of `text`, left to right. Tokens consumed
+/// by a rewrite are skipped; inline pronunciations are never rewritten.
+fn rewrite_tokens(
+    text: &str,
+    tokens: &[Token<'_>],
+    rewrite: impl Fn(usize) -> Option<Rewrite>,
+) -> NormalizedText {
+    let mut out = NormalizedText {
+        text: String::with_capacity(text.len() + 16),
+        replacements: Vec::new(),
+    };
+    let mut copied = 0usize;
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = &tokens[i];
+        let rewrite = if token.core.is_empty() || token.protected {
+            None
+        } else {
+            rewrite(i)
+        };
+        let Some(rewrite) = rewrite else {
+            i += 1;
+            continue;
+        };
+        let last = &tokens[i + rewrite.tokens - 1];
+        let start = token.span.start;
+        let end = last.span.end + usize::from(rewrite.eats_period);
+
+        out.text.push_str(&text[copied..start]);
+        let normalized_start = out.text.len();
+        out.text.push_str(&rewrite.text);
+        out.replacements
+            .push((normalized_start..out.text.len(), start..end));
+        copied = end;
+        i += rewrite.tokens;
+    }
+    out.text.push_str(&text[copied..]);
+    out
+}
+
+/// The token after `i`, if `i` is not followed by punctuation.
+fn next_joined<'t, 'a>(tokens: &'t [Token<'a>], i: usize) -> Option<&'t Token<'a>> {
+    let token = &tokens[i];
+    let next = tokens.get(i + 1)?;
+    (token.trail.is_empty() && !next.protected).then_some(next)
+}
+
+/// Parse a short unsigned number (at most 4 digits).
+fn parse_small(s: &str) -> Option<u64> {
+    if s.is_empty() || s.len() > 4 || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chunked_text_parts, normalize_text};
+    use crate::engines::kokoro::phonemizer::TextPart;
+
+    #[test]
+    fn maps_rewritten_tokens_back_to_source() {
+        let text = "Pay $4.99 now.";
+        let normalized = normalize_text(text, "en-us");
+        let dollars = normalized.as_str().find("dollars").unwrap();
+        assert_eq!(normalized.source_span(dollars..dollars + 7), 4..9);
+        let now = normalized.as_str().find("now").unwrap();
+        assert_eq!(&text[normalized.source_span(now..now + 3)], "now");
+
+        let parts = |text: &'static str, lang| -> Vec<(TextPart, &'static str)> {
+            chunked_text_parts(text, lang, true, 400)
+                .concat()
+                .into_iter()
+                .map(|(part, span)| (part, &text[span]))
+                .collect()
+        };
+        assert_eq!(
+            parts(text, "en-us"),
+            vec![
+                (TextPart::Text("Pay".into()), "Pay"),
+                (
+                    TextPart::Text("four dollars and ninety nine cents".into()),
+                    "$4.99"
+                ),
+                (TextPart::Text("now".into()), "now"),
+                (TextPart::Punct('.'), "."),
+            ]
+        );
+        // Tagged English in a Spanish sentence is normalized as English.
+        assert_eq!(
+            parts("Cuesta [en]$5[/en].", "es"),
+            vec![
+                (TextPart::Text("Cuesta".into()), "Cuesta"),
+                (TextPart::Foreign("five dollars".into(), "en-us"), "$5"),
+                (TextPart::Punct('.'), "."),
+            ]
+        );
+    }
+}
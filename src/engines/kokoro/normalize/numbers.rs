@@ -0,0 +1,677 @@
+//! Cardinal and ordinal number words for the languages handled by
+//! [`Localized`](super::locale::Localized).
+
+use super::locale::Locale;
+
+/// Grammatical gender of the noun a number agrees with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Gender {
+    Masculine,
+    Feminine,
+}
+
+/// Cardinal number words for `n` (below 10^15).
+///
+/// `gender` is the gender of a following noun; `None` gives the form used
+/// when counting, e.g. Spanish `"uno"` rather than `"un"`/`"una"`.
+pub(super) fn cardinal(locale: Locale, n: u64, gender: Option<Gender>) -> String {
+    match locale {
+        Locale::Spanish => spanish(n, gender),
+        Locale::French => french(n, gender),
+        Locale::Italian => italian(n, gender),
+        Locale::Portuguese => portuguese(n, gender),
+        Locale::Hindi => hindi(n),
+    }
+}
+
+/// Ordinal number words for `n` in Spanish, French, Italian or Portuguese:
+/// `ordinal(Spanish, 3, Feminine)` is `"tercera"`.
+pub(super) fn ordinal(locale: Locale, n: u64, gender: Gender) -> String {
+    let masculine = match locale {
+        Locale::Spanish => spanish_ordinal(n),
+        Locale::Portuguese => portuguese_ordinal(n),
+        Locale::Italian => italian_ordinal(n),
+        Locale::French => return french_ordinal(n, gender),
+        Locale::Hindi => None,
+    };
+    let Some(masculine) = masculine else {
+        return cardinal(locale, n, Some(gender));
+    };
+    match gender {
+        Gender::Masculine => masculine,
+        Gender::Feminine => masculine
+            .split(' ')
+            .map(|word| match word.strip_suffix('o') {
+                Some(stem) => format!("{stem}a"),
+                None => word.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+/// The part of a Hindi ordinal before its written suffix: `1ला` is read
+/// `पह` + `ला`, `5वाँ` is read `पाँच` + `वाँ`. `None` if `suffix` does not
+/// fit `n`.
+pub(super) fn hindi_ordinal_stem(n: u64, suffix: &str) -> Option<String> {
+    let (stem, consonant) = match n {
+        1 => ("पह", 'ल'),
+        2 => ("दूस", 'र'),
+        3 => ("तीस", 'र'),
+        4 => ("चौ", 'थ'),
+        6 => ("छ", 'ठ'),
+        _ => return suffix.starts_with('व').then(|| hindi(n)),
+    };
+    suffix.starts_with(consonant).then(|| stem.to_string())
+}
+
+fn words(parts: Vec<String>) -> String {
+    parts.join(" ")
+}
+
+const ES_UNITS: [&str; 30] = [
+    "cero",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+const ES_TENS: [&str; 10] = [
+    "",
+    "",
+    "",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+const ES_HUNDREDS: [&str; 10] = [
+    "",
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+fn spanish(n: u64, gender: Option<Gender>) -> String {
+    if n == 0 {
+        return ES_UNITS[0].to_string();
+    }
+    let mut parts = Vec::new();
+    for (scale, singular, plural) in [
+        (1_000_000_000_000, "billón", "billones"),
+        (1_000_000, "millón", "millones"),
+    ] {
+        match n / scale % 1_000_000 {
+            0 => {}
+            1 => parts.push(format!("un {singular}")),
+            count => parts.push(format!(
+                "{} {plural}",
+                spanish_thousands(count, Some(Gender::Masculine))
+            )),
+        }
+    }
+    if !n.is_multiple_of(1_000_000) {
+        parts.push(spanish_thousands(n % 1_000_000, gender));
+    }
+    words(parts)
+}
+
+fn spanish_thousands(n: u64, gender: Option<Gender>) -> String {
+    let mut parts = Vec::new();
+    match n / 1000 {
+        0 => {}
+        1 => parts.push("mil".to_string()),
+        thousands => parts.push(format!(
+            "{} mil",
+            spanish_hundreds(thousands, Some(gender.unwrap_or(Gender::Masculine)))
+        )),
+    }
+    if !n.is_multiple_of(1000) {
+        parts.push(spanish_hundreds(n % 1000, gender));
+    }
+    words(parts)
+}
+
+fn spanish_hundreds(n: u64, gender: Option<Gender>) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let mut parts = Vec::new();
+    match hundreds {
+        0 => {}
+        1 if rest == 0 => parts.push("cien".to_string()),
+        _ if gender == Some(Gender::Feminine) && hundreds > 1 => {
+            parts.push(ES_HUNDREDS[hundreds].replace("tos", "tas"))
+        }
+        _ => parts.push(ES_HUNDREDS[hundreds].to_string()),
+    }
+    let one = |n: usize| match (n, gender) {
+        (1, Some(Gender::Masculine)) => "un",
+        (1, Some(Gender::Feminine)) => "una",
+        (21, Some(Gender::Masculine)) => "veintiún",
+        (21, Some(Gender::Feminine)) => "veintiuna",
+        _ => ES_UNITS[n],
+    };
+    match rest {
+        0 => {}
+        1..=29 => parts.push(one(rest).to_string()),
+        _ if rest % 10 == 0 => parts.push(ES_TENS[rest / 10].to_string()),
+        _ => parts.push(format!("{} y {}", ES_TENS[rest / 10], one(rest % 10))),
+    }
+    words(parts)
+}
+
+const ES_ORDINAL_UNITS: [&str; 10] = [
+    "", "primero", "segundo", "tercero", "cuarto", "quinto", "sexto", "séptimo", "octavo", "noveno",
+];
+const ES_ORDINAL_TENS: [&str; 10] = [
+    "",
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "cuadragésimo",
+    "quincuagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+
+fn spanish_ordinal(n: u64) -> Option<String> {
+    let (tens, units) = ((n / 10) as usize, (n % 10) as usize);
+    Some(match n {
+        1..=9 => ES_ORDINAL_UNITS[units].to_string(),
+        11 => "undécimo".to_string(),
+        12 => "duodécimo".to_string(),
+        13..=19 => match ES_ORDINAL_UNITS[units].strip_prefix('o') {
+            Some(rest) => format!("decimo{rest}"),
+            None => format!("decimo{}", ES_ORDINAL_UNITS[units]),
+        },
+        10..=99 if units == 0 => ES_ORDINAL_TENS[tens].to_string(),
+        10..=99 => format!("{} {}", ES_ORDINAL_TENS[tens], ES_ORDINAL_UNITS[units]),
+        100 => "centésimo".to_string(),
+        _ => return None,
+    })
+}
+
+const FR_UNITS: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+const FR_TENS: [&str; 7] = [
+    "",
+    "dix",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+
+fn french(n: u64, gender: Option<Gender>) -> String {
+    if n == 0 {
+        return FR_UNITS[0].to_string();
+    }
+    let mut parts = Vec::new();
+    for (scale, name) in [
+        (1_000_000_000_000, "billion"),
+        (1_000_000_000, "milliard"),
+        (1_000_000, "million"),
+    ] {
+        match n / scale % 1000 {
+            0 => {}
+            1 => parts.push(format!("un {name}")),
+            count => parts.push(format!("{} {name}s", french_hundreds(count, None, true))),
+        }
+    }
+    match n / 1000 % 1000 {
+        0 => {}
+        1 => parts.push("mille".to_string()),
+        thousands => parts.push(format!("{} mille", french_hundreds(thousands, None, false))),
+    }
+    if !n.is_multiple_of(1000) {
+        parts.push(french_hundreds(n % 1000, gender, true));
+    }
+    words(parts)
+}
+
+/// `last` is false before "mille", where "cents" and "vingts" lose their `s`.
+fn french_hundreds(n: u64, gender: Option<Gender>, last: bool) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let mut parts = Vec::new();
+    match hundreds {
+        0 => {}
+        1 => parts.push("cent".to_string()),
+        _ if rest == 0 && last => parts.push(format!("{} cents", FR_UNITS[hundreds])),
+        _ => parts.push(format!("{} cent", FR_UNITS[hundreds])),
+    }
+    if rest > 0 {
+        parts.push(french_tens(rest, gender, last));
+    }
+    words(parts)
+}
+
+fn french_tens(n: usize, gender: Option<Gender>, last: bool) -> String {
+    let one = if gender == Some(Gender::Feminine) {
+        "une"
+    } else {
+        "un"
+    };
+    match n {
+        1 => one.to_string(),
+        0..=16 => FR_UNITS[n].to_string(),
+        17..=19 => format!("dix-{}", FR_UNITS[n - 10]),
+        80 if last => "quatre-vingts".to_string(),
+        _ => {
+            let (tens, units) = match n {
+                70..=79 => (6, n - 60),
+                80..=99 => (8, n - 80),
+                _ => (n / 10, n % 10),
+            };
+            let prefix = if tens == 8 {
+                "quatre-vingt"
+            } else {
+                FR_TENS[tens]
+            };
+            match units {
+                0 => prefix.to_string(),
+                1 if tens != 8 => format!("{prefix} et {one}"),
+                11 if tens == 6 => format!("{prefix} et onze"),
+                _ => format!("{prefix}-{}", french_tens(units, gender, last)),
+            }
+        }
+    }
+}
+
+fn french_ordinal(n: u64, gender: Gender) -> String {
+    if n == 1 {
+        return match gender {
+            Gender::Masculine => "premier",
+            Gender::Feminine => "première",
+        }
+        .to_string();
+    }
+    let cardinal = french(n, None);
+    let stem = if let Some(stem) = cardinal.strip_suffix("cinq") {
+        format!("{stem}cinqu")
+    } else if let Some(stem) = cardinal.strip_suffix("neuf") {
+        format!("{stem}neuv")
+    } else {
+        let stem = cardinal.strip_suffix('s').unwrap_or(&cardinal);
+        stem.strip_suffix('e').unwrap_or(stem).to_string()
+    };
+    format!("{stem}ième")
+}
+
+const IT_UNITS: [&str; 20] = [
+    "zero",
+    "uno",
+    "due",
+    "tre",
+    "quattro",
+    "cinque",
+    "sei",
+    "sette",
+    "otto",
+    "nove",
+    "dieci",
+    "undici",
+    "dodici",
+    "tredici",
+    "quattordici",
+    "quindici",
+    "sedici",
+    "diciassette",
+    "diciotto",
+    "diciannove",
+];
+const IT_TENS: [&str; 10] = [
+    "",
+    "",
+    "venti",
+    "trenta",
+    "quaranta",
+    "cinquanta",
+    "sessanta",
+    "settanta",
+    "ottanta",
+    "novanta",
+];
+
+fn italian(n: u64, gender: Option<Gender>) -> String {
+    match (n, gender) {
+        (0, _) => return IT_UNITS[0].to_string(),
+        (1, Some(Gender::Masculine)) => return "un".to_string(),
+        (1, Some(Gender::Feminine)) => return "una".to_string(),
+        _ => {}
+    }
+    let mut parts = Vec::new();
+    for (scale, singular, plural) in [
+        (1_000_000_000, "miliardo", "miliardi"),
+        (1_000_000, "milione", "milioni"),
+    ] {
+        let count = if scale == 1_000_000 {
+            n / scale % 1000
+        } else {
+            n / scale
+        };
+        match count {
+            0 => {}
+            1 => parts.push(format!("un {singular}")),
+            _ => parts.push(format!("{} {plural}", italian_thousands(count))),
+        }
+    }
+    if !n.is_multiple_of(1_000_000) {
+        parts.push(italian_thousands(n % 1_000_000));
+    }
+    words(parts)
+}
+
+/// Italian numbers below a million are written as one word:
+/// 1823 is "milleottocentoventitré".
+fn italian_thousands(n: u64) -> String {
+    let mut word = String::new();
+    match n / 1000 {
+        0 => {}
+        1 => word.push_str("mille"),
+        thousands => {
+            word.push_str(&italian_hundreds(thousands));
+            word.push_str("mila");
+        }
+    }
+    if !n.is_multiple_of(1000) {
+        word.push_str(&italian_hundreds(n % 1000));
+    }
+    // A final "tre" in a compound is stressed: "ventitré".
+    match word.strip_suffix("tre") {
+        Some(stem) if !stem.is_empty() => format!("{stem}tré"),
+        _ => word,
+    }
+}
+
+fn italian_hundreds(n: u64) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let mut word = match hundreds {
+        0 => String::new(),
+        1 => "cento".to_string(),
+        _ => format!("{}cento", IT_UNITS[hundreds]),
+    };
+    if (80..90).contains(&rest) && hundreds > 0 {
+        word.pop();
+    }
+    let (tens, units) = (rest / 10, rest % 10);
+    match rest {
+        0 => {}
+        1..=19 => word.push_str(IT_UNITS[rest]),
+        _ => {
+            let tens = IT_TENS[tens];
+            if units == 1 || units == 8 {
+                word.push_str(&tens[..tens.len() - 1]);
+            } else {
+                word.push_str(tens);
+            }
+            if units > 0 {
+                word.push_str(IT_UNITS[units]);
+            }
+        }
+    }
+    word
+}
+
+const IT_ORDINALS: [&str; 11] = [
+    "", "primo", "secondo", "terzo", "quarto", "quinto", "sesto", "settimo", "ottavo", "nono",
+    "decimo",
+];
+
+fn italian_ordinal(n: u64) -> Option<String> {
+    if let Some(word) = IT_ORDINALS.get(n as usize).filter(|_| n > 0) {
+        return Some(word.to_string());
+    }
+    let cardinal = italian(n, None);
+    let stem = if let Some(stem) = cardinal.strip_suffix("tré") {
+        format!("{stem}tre")
+    } else if cardinal.ends_with("sei") {
+        cardinal
+    } else {
+        let mut stem = cardinal;
+        stem.pop();
+        stem
+    };
+    Some(format!("{stem}esimo"))
+}
+
+const PT_UNITS: [&str; 20] = [
+    "zero",
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "quatorze",
+    "quinze",
+    "dezesseis",
+    "dezessete",
+    "dezoito",
+    "dezenove",
+];
+const PT_TENS: [&str; 10] = [
+    "",
+    "",
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+const PT_HUNDREDS: [&str; 10] = [
+    "",
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+
+fn portuguese(n: u64, gender: Option<Gender>) -> String {
+    if n == 0 {
+        return PT_UNITS[0].to_string();
+    }
+    // (value of the group, its words)
+    let mut groups: Vec<(u64, String)> = Vec::new();
+    for (scale, singular, plural) in [
+        (1_000_000_000_000, "trilhão", "trilhões"),
+        (1_000_000_000, "bilhão", "bilhões"),
+        (1_000_000, "milhão", "milhões"),
+    ] {
+        match n / scale % 1000 {
+            0 => {}
+            1 => groups.push((1, format!("um {singular}"))),
+            count => groups.push((
+                count,
+                format!("{} {plural}", portuguese_hundreds(count, None)),
+            )),
+        }
+    }
+    match n / 1000 % 1000 {
+        0 => {}
+        1 => groups.push((1, "mil".to_string())),
+        thousands => groups.push((
+            thousands,
+            format!("{} mil", portuguese_hundreds(thousands, gender)),
+        )),
+    }
+    if !n.is_multiple_of(1000) {
+        groups.push((n % 1000, portuguese_hundreds(n % 1000, gender)));
+    }
+
+    // The last group is joined with "e" when it is below a hundred or a
+    // round hundred: "dois mil e vinte", "mil e cem", "mil duzentos e dez".
+    let mut out = String::new();
+    let count = groups.len();
+    for (idx, (value, group)) in groups.into_iter().enumerate() {
+        if idx > 0 {
+            out.push_str(if idx == count - 1 && (value < 100 || value % 100 == 0) {
+                " e "
+            } else {
+                " "
+            });
+        }
+        out.push_str(&group);
+    }
+    out
+}
+
+fn portuguese_hundreds(n: u64, gender: Option<Gender>) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let feminine = gender == Some(Gender::Feminine);
+    let unit = |n: usize| match (n, feminine) {
+        (1, true) => "uma",
+        (2, true) => "duas",
+        _ => PT_UNITS[n],
+    };
+    let mut parts = Vec::new();
+    match hundreds {
+        0 => {}
+        1 if rest == 0 => parts.push("cem".to_string()),
+        _ if feminine && hundreds > 1 => parts.push(PT_HUNDREDS[hundreds].replace("os", "as")),
+        _ => parts.push(PT_HUNDREDS[hundreds].to_string()),
+    }
+    match rest {
+        0 => {}
+        1..=19 => parts.push(unit(rest).to_string()),
+        _ => {
+            parts.push(PT_TENS[rest / 10].to_string());
+            if rest % 10 > 0 {
+                parts.push(unit(rest % 10).to_string());
+            }
+        }
+    }
+    parts.join(" e ")
+}
+
+const PT_ORDINAL_UNITS: [&str; 10] = [
+    "", "primeiro", "segundo", "terceiro", "quarto", "quinto", "sexto", "sétimo", "oitavo", "nono",
+];
+const PT_ORDINAL_TENS: [&str; 10] = [
+    "",
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "quadragésimo",
+    "quinquagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+
+fn portuguese_ordinal(n: u64) -> Option<String> {
+    let (tens, units) = ((n / 10) as usize, (n % 10) as usize);
+    Some(match n {
+        1..=9 => PT_ORDINAL_UNITS[units].to_string(),
+        10..=99 if units == 0 => PT_ORDINAL_TENS[tens].to_string(),
+        10..=99 => format!("{} {}", PT_ORDINAL_TENS[tens], PT_ORDINAL_UNITS[units]),
+        100 => "centésimo".to_string(),
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+const HI_UNITS: [&str; 100] = [
+    "शून्य", "एक", "दो", "तीन", "चार", "पाँच", "छह", "सात", "आठ", "नौ",
+    "दस", "ग्यारह", "बारह", "तेरह", "चौदह", "पंद्रह", "सोलह", "सत्रह", "अठारह", "उन्नीस",
+    "बीस", "इक्कीस", "बाईस", "तेईस", "चौबीस", "पच्चीस", "छब्बीस", "सत्ताईस", "अट्ठाईस", "उनतीस",
+    "तीस", "इकतीस", "बत्तीस", "तैंतीस", "चौंतीस", "पैंतीस", "छत्तीस", "सैंतीस", "अड़तीस", "उनतालीस",
+    "चालीस", "इकतालीस", "बयालीस", "तैंतालीस", "चवालीस", "पैंतालीस", "छियालीस", "सैंतालीस", "अड़तालीस", "उनचास",
+    "पचास", "इक्यावन", "बावन", "तिरपन", "चौवन", "पचपन", "छप्पन", "सत्तावन", "अट्ठावन", "उनसठ",
+    "साठ", "इकसठ", "बासठ", "तिरसठ", "चौंसठ", "पैंसठ", "छियासठ", "सड़सठ", "अड़सठ", "उनहत्तर",
+    "सत्तर", "इकहत्तर", "बहत्तर", "तिहत्तर", "चौहत्तर", "पचहत्तर", "छिहत्तर", "सतहत्तर", "अठहत्तर", "उन्यासी",
+    "अस्सी", "इक्यासी", "बयासी", "तिरासी", "चौरासी", "पचासी", "छियासी", "सत्तासी", "अट्ठासी", "नवासी",
+    "नब्बे", "इक्यानवे", "बानवे", "तिरानवे", "चौरानवे", "पंचानवे", "छियानवे", "सत्तानवे", "अट्ठानवे", "निन्यानवे",
+];
+
+/// Hindi numbers use the Indian scales: lakh (10^5) and crore (10^7).
+fn hindi(n: u64) -> String {
+    if n < 100 {
+        return HI_UNITS[n as usize].to_string();
+    }
+    let mut parts = Vec::new();
+    if n >= 10_000_000 {
+        parts.push(format!("{} करोड़", hindi(n / 10_000_000)));
+    }
+    for (count, name) in [
+        (n / 100_000 % 100, "लाख"),
+        (n / 1000 % 100, "हज़ार"),
+        (n / 100 % 10, "सौ"),
+    ] {
+        if count > 0 {
+            parts.push(format!("{} {name}", HI_UNITS[count as usize]));
+        }
+    }
+    if !n.is_multiple_of(100) {
+        parts.push(HI_UNITS[(n % 100) as usize].to_string());
+    }
+    words(parts)
+}
+
+/// A Hindi year as usually read: 1990 is "उन्नीस सौ नब्बे".
+pub(super) fn hindi_year(year: u64) -> String {
+    match year {
+        1100..=1999 if year.is_multiple_of(100) => {
+            format!("{} सौ", HI_UNITS[(year / 100) as usize])
+        }
+        1100..=1999 => format!(
+            "{} सौ {}",
+            HI_UNITS[(year / 100) as usize],
+            HI_UNITS[(year % 100) as usize]
+        ),
+        _ => hindi(year),
+    }
+}
@@ -1,10 +1,14 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
+use std::ops::Range;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
+use std::time::Duration;
 
+use super::espeak_worker::EspeakWorker;
 use super::model::KokoroError;
+use crate::segment::is_abbreviation_period;
 
 /// Configuration for locating the espeak-ng binary and its data directory.
 ///
@@ -18,32 +22,258 @@ pub struct EspeakConfig {
     pub data_path: Option<PathBuf>,
 }
 
+/// Converts text to IPA for Kokoro.
+///
+/// [`KokoroEngine`](super::KokoroEngine) uses [`EspeakPhonemizer`] by default;
+/// implement this trait to plug in another G2P backend, a dictionary-based
+/// phonemizer or a test stub via
+/// [`KokoroEngine::with_phonemizer`](super::KokoroEngine::with_phonemizer).
+///
+/// The returned IPA is mapped to tokens character by character through the
+/// model vocabulary: whitespace separates words and characters outside the
+/// vocabulary are dropped.
+pub trait Phonemizer: Send {
+    /// Convert `text` to an IPA string.
+    ///
+    /// `lang` is an espeak-ng language code as returned by [`voice_lang`]
+    /// (e.g. `"en-us"`, `"fr"`, `"cmn"`), or the language of a stretch of
+    /// text in another language than the voice's. Punctuation has already been
+    /// split off, so `text` is usually a run of words.
+    fn phonemize(&mut self, text: &str, lang: &str) -> Result<String, KokoroError>;
+
+    /// Convert several independent segments, returning one IPA string each.
+    ///
+    /// The default calls [`phonemize`](Self::phonemize) once per segment.
+    /// Override it when one call for many segments is cheaper.
+    fn phonemize_batch(
+        &mut self,
+        segments: &[&str],
+        lang: &str,
+    ) -> Result<Vec<String>, KokoroError> {
+        segments
+            .iter()
+            .map(|segment| self.phonemize(segment, lang))
+            .collect()
+    }
+}
+
+/// How long a persistent espeak-ng worker may take to answer one request.
+const DEFAULT_WORKER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The default [`Phonemizer`], backed by espeak-ng.
+///
+/// Keeps one long-lived espeak-ng process per language and reuses it across
+/// calls, which avoids a process spawn per request. A worker that crashes or
+/// does not answer within the timeout is restarted once before the request
+/// fails. If espeak-ng cannot be driven this way (e.g. a build that does not
+/// flush output per line), the phonemizer falls back to one process per call
+/// for that language.
+#[derive(Debug)]
+pub struct EspeakPhonemizer {
+    config: EspeakConfig,
+    persistent: bool,
+    timeout: Duration,
+    /// Running workers keyed by language code.
+    workers: HashMap<String, EspeakWorker>,
+    /// Languages whose worker failed to start; these use one process per call.
+    one_shot_langs: HashSet<String>,
+}
+
+impl Default for EspeakPhonemizer {
+    fn default() -> Self {
+        Self::new(EspeakConfig::default())
+    }
+}
+
+impl Clone for EspeakPhonemizer {
+    /// Clones share configuration but start their own workers.
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            persistent: self.persistent,
+            timeout: self.timeout,
+            workers: HashMap::new(),
+            one_shot_langs: self.one_shot_langs.clone(),
+        }
+    }
+}
+
+impl EspeakPhonemizer {
+    /// Create a phonemizer that runs espeak-ng as configured.
+    pub fn new(config: EspeakConfig) -> Self {
+        Self {
+            config,
+            persistent: true,
+            timeout: DEFAULT_WORKER_TIMEOUT,
+            workers: HashMap::new(),
+            one_shot_langs: HashSet::new(),
+        }
+    }
+
+    /// Maximum time a worker may take to answer one request. Default: 10 s.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Keep espeak-ng processes alive between calls (the default), or spawn
+    /// a fresh process for every call when `false`.
+    pub fn with_persistent_workers(mut self, persistent: bool) -> Self {
+        self.persistent = persistent;
+        if !persistent {
+            self.workers.clear();
+        }
+        self
+    }
+
+    /// The espeak-ng binary and data paths in use.
+    pub fn config(&self) -> &EspeakConfig {
+        &self.config
+    }
+
+    /// Phonemize segments and return espeak-ng's output lines.
+    fn run(&mut self, segments: &[&str], lang: &str) -> Result<Vec<String>, KokoroError> {
+        if self.persistent && !self.one_shot_langs.contains(lang) {
+            if let Some(lines) = self.run_worker(segments, lang)? {
+                return Ok(lines);
+            }
+        }
+
+        let output = run_espeak(&segments.join("\n"), lang, &self.config)?;
+        Ok(output.lines().map(str::to_string).collect())
+    }
+
+    /// Send a request to the worker for `lang`, restarting it once on failure.
+    ///
+    /// Returns `Ok(None)` when no worker can be started for the language.
+    fn run_worker(
+        &mut self,
+        segments: &[&str],
+        lang: &str,
+    ) -> Result<Option<Vec<String>>, KokoroError> {
+        let Some(worker) = self.worker(lang)? else {
+            return Ok(None);
+        };
+        match worker.request(segments) {
+            Ok(lines) => return Ok(Some(lines)),
+            Err(e) => {
+                log::warn!("espeak-ng worker for '{lang}' failed ({e}), restarting");
+                self.workers.remove(lang);
+            }
+        }
+
+        let Some(worker) = self.worker(lang)? else {
+            return Ok(None);
+        };
+        let result = worker.request(segments);
+        if result.is_err() {
+            self.workers.remove(lang);
+        }
+        result.map(Some)
+    }
+
+    /// The worker for `lang`, started on first use.
+    ///
+    /// A worker that fails to start (other than espeak-ng being missing)
+    /// switches the language to one process per call.
+    fn worker(&mut self, lang: &str) -> Result<Option<&mut EspeakWorker>, KokoroError> {
+        if !self.workers.contains_key(lang) {
+            match EspeakWorker::spawn(lang, &self.config, self.timeout) {
+                Ok(worker) => {
+                    self.workers.insert(lang.to_string(), worker);
+                }
+                Err(KokoroError::PhonemizerFailed(e)) => {
+                    log::warn!(
+                        "espeak-ng worker for '{lang}' unavailable ({e}), \
+                         using one process per call"
+                    );
+                    self.one_shot_langs.insert(lang.to_string());
+                    return Ok(None);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self.workers.get_mut(lang))
+    }
+}
+
+impl Phonemizer for EspeakPhonemizer {
+    fn phonemize(&mut self, text: &str, lang: &str) -> Result<String, KokoroError> {
+        Ok(self.run(&[text], lang)?.join("\n"))
+    }
+
+    fn phonemize_batch(
+        &mut self,
+        segments: &[&str],
+        lang: &str,
+    ) -> Result<Vec<String>, KokoroError> {
+        let lines = self.run(segments, lang)?;
+
+        // espeak-ng should emit one line per input line for stdin mode.
+        // If this assumption breaks, fall back to one request per segment.
+        if lines.len() != segments.len() {
+            return segments
+                .iter()
+                .map(|segment| self.phonemize(segment, lang))
+                .collect();
+        }
+
+        Ok(lines)
+    }
+}
+
+/// Voice name prefixes of the official Kokoro voices and their espeak-ng
+/// language codes.
+///
+/// Other prefixes can be mapped with
+/// [`KokoroModelParams::voice_languages`](super::KokoroModelParams::voice_languages)
+/// or a voice's metadata.
+pub const DEFAULT_VOICE_LANGUAGES: &[(&str, &str)] = &[
+    ("af", "en-us"),
+    ("am", "en-us"),
+    ("bf", "en-gb"),
+    ("bm", "en-gb"),
+    ("ef", "es"),
+    ("em", "es"),
+    ("ff", "fr"),
+    ("hf", "hi"),
+    ("hm", "hi"),
+    ("if", "it"),
+    ("im", "it"),
+    ("jf", "ja"),
+    ("jm", "ja"),
+    ("pf", "pt-br"),
+    ("pm", "pt-br"),
+    ("zf", "cmn"),
+    ("zm", "cmn"),
+];
+
 /// Map a voice name prefix to an espeak-ng language code.
 ///
 /// Voice names follow the pattern `{prefix}_{name}` where the two-character
-/// prefix encodes the language.
+/// prefix encodes the language (see [`DEFAULT_VOICE_LANGUAGES`]). Unknown
+/// prefixes map to `"en-us"`. This only knows the default prefixes; the
+/// engine also applies configured overrides and voice metadata (see
+/// [`KokoroModel::voice_language`](super::model::KokoroModel::voice_language)).
 pub fn voice_lang(voice: &str) -> &'static str {
-    let prefix = &voice[..voice.len().min(2)];
-    match prefix {
-        "af" | "am" => "en-us",
-        "bf" | "bm" => "en-gb",
-        "ef" | "em" => "es",
-        "ff" => "fr",
-        "hf" | "hm" => "hi",
-        "if" | "im" => "it",
-        "jf" | "jm" => "ja",
-        "pf" | "pm" => "pt-br",
-        "zf" | "zm" => "cmn",
-        _ => "en-us",
-    }
-}
-
-/// Convert text to Kokoro phoneme token IDs via espeak-ng.
+    voice_prefix_lang(voice).unwrap_or("en-us")
+}
+
+/// The language encoded in a voice name prefix, if the prefix is known.
+pub(crate) fn voice_prefix_lang(voice: &str) -> Option<&'static str> {
+    DEFAULT_VOICE_LANGUAGES
+        .iter()
+        .find(|(prefix, _)| voice.starts_with(prefix))
+        .map(|&(_, lang)| lang)
+}
+
+/// Convert text to Kokoro phoneme token IDs.
 ///
 /// # Arguments
 /// - `text`: The input text to phonemize
 /// - `lang`: espeak-ng language code (e.g. `"en-us"`, `"fr"`, `"ja"`, `"cmn"`)
 /// - `vocab`: Mapping from IPA characters to token IDs
+/// - `phonemizer`: Text-to-IPA backend, e.g. [`EspeakPhonemizer`]
 ///
 /// # Returns
 /// A `Vec<i64>` of token IDs. Characters not in the vocab are silently dropped,
@@ -52,64 +282,320 @@ pub fn phonemize(
     text: &str,
     lang: &str,
     vocab: &HashMap<char, i64>,
-    espeak: &EspeakConfig,
+    phonemizer: &mut dyn Phonemizer,
 ) -> Result<Vec<i64>, KokoroError> {
-    let parts = split_text_parts(text);
+    Ok(phonemize_words(text, lang, vocab, phonemizer)?.ids)
+}
+
+/// Phoneme token IDs for a text, with the source word each token belongs to.
+#[derive(Debug, Clone, Default)]
+pub struct PhonemizedText {
+    /// Token IDs, identical to what [`phonemize`] returns.
+    pub ids: Vec<i64>,
+    /// Source words in text order.
+    pub words: Vec<WordTokens>,
+}
+
+/// Alignment between one source word and its phoneme tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordTokens {
+    /// Byte range of the word in the source text, including directly
+    /// attached punctuation (e.g. `"world."`).
+    pub text_span: Range<usize>,
+    /// IPA produced for the word.
+    pub phonemes: String,
+    /// Range into [`PhonemizedText::ids`] covering the word, the separator
+    /// after it and any punctuation tokens that follow.
+    pub tokens: Range<usize>,
+}
+
+/// Like [`phonemize`], but also maps tokens back to the words they came from.
+///
+/// Phonemizers emit one IPA word per source word in the common case; when the
+/// counts differ (numbers, abbreviations) IPA words are distributed over the
+/// source words in order.
+pub fn phonemize_words(
+    text: &str,
+    lang: &str,
+    vocab: &HashMap<char, i64>,
+    phonemizer: &mut dyn Phonemizer,
+) -> Result<PhonemizedText, KokoroError> {
+    phonemize_parts(
+        text,
+        split_text_parts_with_spans(text, lang),
+        lang,
+        vocab,
+        phonemizer,
+    )
+}
+
+/// Phonemize already-split parts whose spans refer to `text`.
+pub(crate) fn phonemize_parts(
+    text: &str,
+    parts: Vec<(TextPart, Range<usize>)>,
+    lang: &str,
+    vocab: &HashMap<char, i64>,
+    phonemizer: &mut dyn Phonemizer,
+) -> Result<PhonemizedText, KokoroError> {
     if parts.is_empty() {
-        return Ok(Vec::new());
+        return Ok(PhonemizedText::default());
     }
 
-    let text_segments: Vec<&str> = parts
+    let text_segments: Vec<(&str, &str)> = parts
         .iter()
-        .filter_map(|part| match part {
-            TextPart::Text(segment) => Some(segment.as_str()),
-            TextPart::Punct(_) => None,
+        .filter_map(|(part, _)| match part {
+            TextPart::Text(segment) => Some((segment.as_str(), lang)),
+            TextPart::Foreign(segment, segment_lang) => Some((segment.as_str(), *segment_lang)),
+            TextPart::Punct(_) | TextPart::Ipa(_) => None,
         })
         .collect();
 
-    let segment_ids = if text_segments.is_empty() {
-        Vec::new()
-    } else {
-        phonemize_segments_batch(&text_segments, lang, vocab, espeak)?
-    };
+    // One batch per language, in order of first appearance.
+    let mut segment_ipa = vec![String::new(); text_segments.len()];
+    let mut done = HashSet::new();
+    for &(_, batch_lang) in &text_segments {
+        if !done.insert(batch_lang) {
+            continue;
+        }
+        let (indices, segments): (Vec<usize>, Vec<&str>) = text_segments
+            .iter()
+            .enumerate()
+            .filter(|(_, &(_, segment_lang))| segment_lang == batch_lang)
+            .map(|(i, &(segment, _))| (i, segment))
+            .unzip();
+        let ipa = phonemizer.phonemize_batch(&segments, batch_lang)?;
+        if ipa.len() != segments.len() {
+            return Err(KokoroError::PhonemizerFailed(format!(
+                "expected {} segments from phonemizer, got {}",
+                segments.len(),
+                ipa.len()
+            )));
+        }
+        for (i, ipa) in indices.into_iter().zip(ipa) {
+            segment_ipa[i] = ipa;
+        }
+    }
+
+    Ok(assemble_phonemized(text, parts, &segment_ipa, vocab))
+}
 
-    let mut ids = Vec::new();
+/// Combine per-segment IPA and punctuation parts into tokens and word alignments.
+fn assemble_phonemized(
+    text: &str,
+    parts: Vec<(TextPart, Range<usize>)>,
+    segment_ipa: &[String],
+    vocab: &HashMap<char, i64>,
+) -> PhonemizedText {
+    let mut result = PhonemizedText::default();
     let mut segment_index = 0usize;
-    for part in parts {
+    let mut after_word = false;
+    for (part, span) in parts {
+        // Adjacent word parts (text next to inline IPA) need an explicit separator.
+        if after_word && !matches!(part, TextPart::Punct(_)) {
+            if let Some(&space) = vocab.get(&' ') {
+                result.ids.push(space);
+                if let Some(word) = result.words.last_mut() {
+                    word.tokens.end = result.ids.len();
+                }
+            }
+        }
+        after_word = !matches!(part, TextPart::Punct(_));
+
         match part {
-            TextPart::Text(_) => {
-                if let Some(chunk) = segment_ids.get(segment_index) {
-                    ids.extend_from_slice(chunk);
+            TextPart::Text(_) | TextPart::Foreign(..) => {
+                if let Some(ipa) = segment_ipa.get(segment_index) {
+                    push_segment_words(&mut result, text, span, ipa, vocab);
                 }
                 segment_index += 1;
             }
+            TextPart::Ipa(ipa) => {
+                let start = result.ids.len();
+                result.ids.extend(
+                    ipa_to_words(&ipa, vocab)
+                        .into_iter()
+                        .flat_map(|word| word.ids),
+                );
+                result.words.push(WordTokens {
+                    text_span: span,
+                    phonemes: ipa.trim().to_string(),
+                    tokens: start..result.ids.len(),
+                });
+            }
             TextPart::Punct(ch) => {
                 if let Some(&id) = vocab.get(&ch) {
-                    ids.push(id);
+                    result.ids.push(id);
+                }
+                if let Some(word) = result.words.last_mut() {
+                    word.tokens.end = result.ids.len();
+                    if word.text_span.end == span.start {
+                        word.text_span.end = span.end;
+                    }
                 }
             }
         }
     }
 
-    Ok(ids)
+    // Several parts can come from one source word (e.g. a normalized token
+    // containing punctuation); report it once.
+    result.words.dedup_by(|next, word| {
+        if next.text_span != word.text_span {
+            return false;
+        }
+        word.tokens.end = word.tokens.end.max(next.tokens.end);
+        if !next.phonemes.is_empty() {
+            if !word.phonemes.is_empty() {
+                word.phonemes.push(' ');
+            }
+            word.phonemes.push_str(&next.phonemes);
+        }
+        true
+    });
+    result
+}
+
+/// Append the tokens of one text segment, aligning IPA words to source words.
+fn push_segment_words(
+    result: &mut PhonemizedText,
+    text: &str,
+    span: Range<usize>,
+    ipa: &str,
+    vocab: &HashMap<char, i64>,
+) {
+    let source_words = word_spans(text, span);
+    let ipa_words = ipa_to_words(ipa, vocab);
+    if source_words.is_empty() {
+        result
+            .ids
+            .extend(ipa_words.into_iter().flat_map(|word| word.ids));
+        return;
+    }
+
+    let base = result.words.len();
+    result
+        .words
+        .extend(source_words.iter().map(|word_span| WordTokens {
+            text_span: word_span.clone(),
+            phonemes: String::new(),
+            tokens: 0..0,
+        }));
+
+    // `next` is the first source word that has not received any IPA yet.
+    let mut next = 0usize;
+    let n_ipa = ipa_words.len();
+    for (i, ipa_word) in ipa_words.into_iter().enumerate() {
+        let target = i * source_words.len() / n_ipa;
+        let start = result.ids.len();
+        if next < target {
+            for word in &mut result.words[base + next..base + target] {
+                word.tokens = start..start;
+            }
+            next = target;
+        }
+
+        result.ids.extend_from_slice(&ipa_word.ids);
+        let end = result.ids.len();
+        let word = &mut result.words[base + target];
+        if next == target {
+            word.tokens = start..end;
+            next += 1;
+        } else {
+            word.tokens.end = end;
+            word.phonemes.push(' ');
+        }
+        word.phonemes.push_str(&ipa_word.phonemes);
+    }
+
+    let end = result.ids.len();
+    for word in &mut result.words[base + next..] {
+        word.tokens = end..end;
+    }
+}
+
+/// Byte ranges of whitespace-separated words inside `span`.
+fn word_spans(text: &str, span: Range<usize>) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (idx, ch) in text[span.clone()].char_indices() {
+        let idx = span.start + idx;
+        if ch.is_whitespace() {
+            if let Some(word_start) = start.take() {
+                words.push(word_start..idx);
+            }
+        } else if start.is_none() {
+            start = Some(idx);
+        }
+    }
+    if let Some(word_start) = start {
+        words.push(word_start..span.end);
+    }
+    words
+}
+
+/// Tokenize a caller-supplied IPA string, one word per whitespace-separated run.
+pub(crate) fn phonemize_ipa(ipa: &str, vocab: &HashMap<char, i64>) -> PhonemizedText {
+    assemble_phonemized(ipa, ipa_parts(ipa), &[], vocab)
+}
+
+/// Split a caller-supplied IPA string into one [`TextPart::Ipa`] per word.
+fn ipa_parts(ipa: &str) -> Vec<(TextPart, Range<usize>)> {
+    word_spans(ipa, 0..ipa.len())
+        .into_iter()
+        .map(|span| (TextPart::Ipa(ipa[span.clone()].to_string()), span))
+        .collect()
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum TextPart {
+pub(crate) enum TextPart {
+    /// Text to be phonemized.
     Text(String),
+    /// Text in another language than the voice's, phonemized with the given
+    /// espeak-ng code.
+    Foreign(String, &'static str),
+    /// Boundary punctuation, mapped straight to its token.
     Punct(char),
+    /// IPA supplied by the caller, bypassing the phonemizer. Spoken as one word.
+    Ipa(String),
 }
 
+#[cfg(test)]
 fn split_text_parts(text: &str) -> Vec<TextPart> {
+    split_text_parts_with_spans(text, "en-us")
+        .into_iter()
+        .map(|(part, _)| part)
+        .collect()
+}
+
+/// Split text into text and punctuation parts, with the source byte range of
+/// each part. Text parts have their internal whitespace collapsed; their span
+/// runs from the first to the last non-whitespace character.
+///
+/// Periods of abbreviations and initials in language `lang` (`"Dr."`,
+/// `"U.S."`) stay inside the text. Inline pronunciations written as
+/// `[Kokoro](/kˈOkəɹO/)` become [`TextPart::Ipa`] parts whose span covers the
+/// bracketed display text.
+pub(crate) fn split_text_parts_with_spans(text: &str, lang: &str) -> Vec<(TextPart, Range<usize>)> {
     let mut parts = Vec::new();
     let mut current = String::new();
+    let mut current_span: Option<Range<usize>> = None;
+    // End of an inline pronunciation that has already been emitted.
+    let mut skip_until = 0usize;
 
     for (idx, ch) in text.char_indices() {
+        if idx < skip_until {
+            continue;
+        }
+        if let Some(inline) = inline_pronunciation(text, idx) {
+            flush_text_part(&mut parts, &mut current, &mut current_span);
+            parts.push((TextPart::Ipa(text[inline.ipa].to_string()), inline.display));
+            skip_until = inline.end;
+            continue;
+        }
+
         let ch_len = ch.len_utf8();
         if let Some(punct) = map_boundary_punctuation(ch) {
-            if !is_numeric_connector_between_digits(text, idx, ch_len, ch) {
-                flush_text_part(&mut parts, &mut current);
-                parts.push(TextPart::Punct(punct));
+            if !is_inner_period(text, idx, ch_len, ch, lang) {
+                flush_text_part(&mut parts, &mut current, &mut current_span);
+                parts.push((TextPart::Punct(punct), idx..idx + ch_len));
                 continue;
             }
         }
@@ -122,78 +608,386 @@ fn split_text_parts(text: &str) -> Vec<TextPart> {
         }
 
         current.push(ch);
+        match current_span.as_mut() {
+            Some(span) => span.end = idx + ch_len,
+            None => current_span = Some(idx..idx + ch_len),
+        }
     }
 
-    flush_text_part(&mut parts, &mut current);
+    flush_text_part(&mut parts, &mut current, &mut current_span);
     parts
 }
 
-fn flush_text_part(parts: &mut Vec<TextPart>, current: &mut String) {
+fn flush_text_part(
+    parts: &mut Vec<(TextPart, Range<usize>)>,
+    current: &mut String,
+    current_span: &mut Option<Range<usize>>,
+) {
     let trimmed = current.trim();
-    if trimmed.is_empty() {
-        current.clear();
-        return;
+    let span = current_span.take();
+    if let (false, Some(span)) = (trimmed.is_empty(), span) {
+        parts.push((TextPart::Text(trimmed.to_string()), span));
     }
-    parts.push(TextPart::Text(trimmed.to_string()));
     current.clear();
 }
 
+/// An inline pronunciation override: `[display](/ipa/)`.
+struct InlinePronunciation {
+    /// Byte range of the display text, without brackets.
+    display: Range<usize>,
+    /// Byte range of the IPA, without slashes.
+    ipa: Range<usize>,
+    /// Byte offset just past the closing `)`.
+    end: usize,
+}
+
+/// Byte ranges of all inline pronunciations (`[display](/ipa/)`) in `text`.
+pub(crate) fn inline_pronunciation_spans(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut from = 0;
+    while let Some(offset) = text[from..].find('[') {
+        let idx = from + offset;
+        match inline_pronunciation(text, idx) {
+            Some(inline) => {
+                spans.push(idx..inline.end);
+                from = inline.end;
+            }
+            None => from = idx + 1,
+        }
+    }
+    spans
+}
+
+/// Parse an inline pronunciation starting at byte `idx`, if there is one.
+///
+/// Neither part may be empty or span lines, and the display text cannot
+/// contain brackets.
+fn inline_pronunciation(text: &str, idx: usize) -> Option<InlinePronunciation> {
+    let rest = text[idx..].strip_prefix('[')?;
+    let close = rest.find([']', '[', '\n'])?;
+    let display = &rest[..close];
+    let rest = rest[close..].strip_prefix("](/")?;
+    let ipa_len = rest.find(['/', '\n'])?;
+    if !rest[ipa_len..].starts_with("/)") || ipa_len == 0 || display.trim().is_empty() {
+        return None;
+    }
+
+    let display_start = idx + 1;
+    let ipa_start = display_start + close + "](/".len();
+    let display_trimmed = display.trim();
+    let display_offset = display_start + (display.len() - display.trim_start().len());
+    Some(InlinePronunciation {
+        display: display_offset..display_offset + display_trimmed.len(),
+        ipa: ipa_start..ipa_start + ipa_len,
+        end: ipa_start + ipa_len + "/)".len(),
+    })
+}
+
+/// Split text into sentence byte ranges for incremental synthesis, with
+/// English abbreviations. See [`split_sentences_for`].
+pub fn split_sentences(text: &str) -> Vec<Range<usize>> {
+    split_sentences_for(text, "en-us")
+}
+
+/// Split text in language `lang` into sentence byte ranges for incremental
+/// synthesis.
+///
+/// Uses the same boundary rules as [`phonemize`]: a sentence ends at `.`, `!`,
+/// `?`, `…`, their CJK and full-width forms (`。！？．`) or a line break
+/// (decimal points and thousands separators between digits are not
+/// boundaries). Trailing closing quotes/brackets, including `」` and `』`, stay
+/// with the sentence they close. Periods of abbreviations and initials
+/// (`"Dr. Smith"`, `"U.S. Army"`, `"J. K. Rowling"`) and dotted words
+/// (`"Node.js"`) do not end sentences. Returned ranges are trimmed of
+/// surrounding whitespace.
+pub fn split_sentences_for(text: &str, lang: &str) -> Vec<Range<usize>> {
+    let mut sentences = Vec::new();
+    let mut start = 0usize;
+    for end in sentence_ends(text, lang) {
+        push_trimmed_span(&mut sentences, text, start..end);
+        start = end;
+    }
+    push_trimmed_span(&mut sentences, text, start..text.len());
+    sentences
+}
+
+/// Length of the prefix of `text` made of sentences that are known to be complete.
+///
+/// A sentence only counts as complete once text follows its terminator,
+/// because more input could still extend it (`"2."` may become `"2.5"`, `"!"`
+/// may become `"!?"`, `"Dr. "` may become `"Dr. Smith"`). Used for
+/// incremental input where text arrives in pieces.
+pub(crate) fn completed_sentences_len(text: &str, lang: &str) -> usize {
+    sentence_ends(text, lang)
+        .into_iter()
+        .rev()
+        .find(|&end| !text[end..].trim().is_empty())
+        .unwrap_or(0)
+}
+
+/// Split text in language `lang` into chunks of at most `max_chars`
+/// characters to synthesize separately.
+///
+/// Consecutive sentences (see [`split_sentences_for`]) are packed into one
+/// chunk while they fit. A sentence that is too long is cut at clause
+/// punctuation (`,` `;` `:` `—` `…` and their CJK forms), a clause that is
+/// too long between words, and only a single word longer than `max_chars`
+/// is cut inside. Inline pronunciations are never cut. Returned ranges are
+/// trimmed of surrounding whitespace.
+pub fn split_text_chunks(text: &str, lang: &str, max_chars: usize) -> Vec<Range<usize>> {
+    let chunker = Chunker {
+        text,
+        lang,
+        max_chars: max_chars.max(1),
+        inline: inline_pronunciation_spans(text),
+    };
+    let mut chunks = Vec::new();
+    chunker.pack(
+        split_sentences_for(text, lang),
+        ChunkLevel::Sentence,
+        &mut chunks,
+    );
+    chunks
+}
+
+/// Boundaries a chunk can be cut at, coarsest first.
+#[derive(Clone, Copy)]
+enum ChunkLevel {
+    Sentence,
+    Clause,
+    Word,
+    Char,
+}
+
+struct Chunker<'a> {
+    text: &'a str,
+    lang: &'a str,
+    max_chars: usize,
+    /// Inline pronunciations, which must stay in one chunk.
+    inline: Vec<Range<usize>>,
+}
+
+impl Chunker<'_> {
+    fn fits(&self, span: Range<usize>) -> bool {
+        self.text[span].chars().nth(self.max_chars).is_none()
+    }
+
+    /// Greedily merge neighbouring `spans` of `level` into chunks that fit,
+    /// cutting spans that are too long on their own at the next finer level.
+    fn pack(&self, spans: Vec<Range<usize>>, level: ChunkLevel, chunks: &mut Vec<Range<usize>>) {
+        let mut current: Option<Range<usize>> = None;
+        for span in spans {
+            if !self.fits(span.clone()) {
+                chunks.extend(current.take());
+                let finer = match level {
+                    ChunkLevel::Sentence => ChunkLevel::Clause,
+                    ChunkLevel::Clause => ChunkLevel::Word,
+                    ChunkLevel::Word | ChunkLevel::Char => ChunkLevel::Char,
+                };
+                match level {
+                    ChunkLevel::Char => chunks.push(span),
+                    _ => self.pack(self.split(span, finer), finer, chunks),
+                }
+                continue;
+            }
+            current = match current {
+                Some(chunk) if self.fits(chunk.start..span.end) => Some(chunk.start..span.end),
+                Some(chunk) => {
+                    chunks.push(chunk);
+                    Some(span)
+                }
+                None => Some(span),
+            };
+        }
+        chunks.extend(current);
+    }
+
+    /// Cut `span` at every boundary of `level`.
+    fn split(&self, span: Range<usize>, level: ChunkLevel) -> Vec<Range<usize>> {
+        let slice = &self.text[span.clone()];
+        let mut cuts: Vec<usize> = match level {
+            ChunkLevel::Sentence => unreachable!("sentences are split before packing"),
+            ChunkLevel::Clause => {
+                let mut cuts = Vec::new();
+                let mut chars = slice.char_indices().peekable();
+                while let Some((offset, ch)) = chars.next() {
+                    let idx = span.start + offset;
+                    let is_clause_end =
+                        matches!(
+                            map_boundary_punctuation(ch),
+                            Some(',' | ';' | ':' | '—' | '…')
+                        ) && !is_inner_period(self.text, idx, ch.len_utf8(), ch, self.lang);
+                    if !is_clause_end {
+                        continue;
+                    }
+                    let mut end = idx + ch.len_utf8();
+                    while let Some(&(next_offset, next)) = chars.peek() {
+                        if !is_closing_punctuation(next) {
+                            break;
+                        }
+                        end = span.start + next_offset + next.len_utf8();
+                        chars.next();
+                    }
+                    cuts.push(end);
+                }
+                cuts
+            }
+            ChunkLevel::Word => slice
+                .char_indices()
+                .filter(|&(_, ch)| ch.is_whitespace())
+                .map(|(offset, _)| span.start + offset)
+                .collect(),
+            ChunkLevel::Char => slice
+                .char_indices()
+                .skip(self.max_chars)
+                .step_by(self.max_chars)
+                .map(|(offset, _)| span.start + offset)
+                .collect(),
+        };
+        cuts.retain(|&cut| {
+            !self
+                .inline
+                .iter()
+                .any(|inline| inline.start < cut && cut < inline.end)
+        });
+
+        let mut spans = Vec::new();
+        let mut start = span.start;
+        for cut in cuts {
+            push_trimmed_span(&mut spans, self.text, start..cut);
+            start = cut;
+        }
+        push_trimmed_span(&mut spans, self.text, start..span.end);
+        spans
+    }
+}
+
+/// Byte offsets just past each sentence terminator (and any closers after it).
+fn sentence_ends(text: &str, lang: &str) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    let mut skip_until = 0usize;
+
+    while let Some((idx, ch)) = chars.next() {
+        // Terminators inside inline pronunciations do not end sentences.
+        if idx < skip_until {
+            continue;
+        }
+        if let Some(inline) = inline_pronunciation(text, idx) {
+            skip_until = inline.end;
+            continue;
+        }
+
+        let ch_len = ch.len_utf8();
+        if !is_sentence_terminator(ch) || is_inner_period(text, idx, ch_len, ch, lang) {
+            continue;
+        }
+
+        let mut end = idx + ch_len;
+        while let Some(&(next_idx, next)) = chars.peek() {
+            if is_sentence_terminator(next) || is_closing_punctuation(next) {
+                end = next_idx + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        ends.push(end);
+    }
+
+    ends
+}
+
+fn is_sentence_terminator(ch: char) -> bool {
+    matches!(
+        ch,
+        '.' | '!' | '?' | '…' | '\n' | '\r' | '。' | '．' | '｡' | '！' | '？'
+    )
+}
+
+fn is_closing_punctuation(ch: char) -> bool {
+    matches!(
+        ch,
+        '"' | ')' | '\u{201d}' | '」' | '』' | '）' | '》' | '〉' | '】' | '＂'
+    )
+}
+
+fn push_trimmed_span(spans: &mut Vec<Range<usize>>, text: &str, span: Range<usize>) {
+    let slice = &text[span.clone()];
+    let trimmed_start = span.start + (slice.len() - slice.trim_start().len());
+    let trimmed_end = span.start + slice.trim_end().len();
+    if trimmed_start < trimmed_end {
+        spans.push(trimmed_start..trimmed_end);
+    }
+}
+
 fn map_boundary_punctuation(ch: char) -> Option<char> {
     match ch {
         '.' | '!' | '?' | ',' | ';' | ':' | '—' | '…' | '"' | '(' | ')' | '\u{201c}'
         | '\u{201d}' => Some(ch),
         '\n' | '\r' => Some('.'),
+        // CJK and full-width forms, as used by Japanese and Mandarin text.
+        '。' | '．' | '｡' => Some('.'),
+        '！' => Some('!'),
+        '？' => Some('?'),
+        '，' | '、' | '､' => Some(','),
+        '；' => Some(';'),
+        '：' => Some(':'),
+        '＂' => Some('"'),
+        '（' | '【' => Some('('),
+        '）' | '】' => Some(')'),
+        '「' | '『' | '《' | '〈' => Some('\u{201c}'),
+        '」' | '』' | '》' | '〉' => Some('\u{201d}'),
+        '⋯' => Some('…'),
+        '―' => Some('—'),
         _ => None,
     }
 }
 
+/// Whether punctuation at byte `idx` is part of a word or number rather than
+/// a boundary: a decimal or thousands separator, or the period of an
+/// abbreviation.
+fn is_inner_period(text: &str, idx: usize, ch_len: usize, ch: char, lang: &str) -> bool {
+    is_numeric_connector_between_digits(text, idx, ch_len, ch)
+        || (ch == '.' && is_abbreviation_period(text, idx, lang))
+}
+
 fn is_numeric_connector_between_digits(text: &str, idx: usize, ch_len: usize, ch: char) -> bool {
-    if !matches!(ch, '.' | ',') {
+    if !matches!(ch, '.' | ',' | '．' | '，') {
         return false;
     }
 
     let prev = text[..idx].chars().next_back();
     let next = text[idx + ch_len..].chars().next();
+    let is_digit = |c: char| c.is_ascii_digit() || ('０'..='９').contains(&c);
 
     matches!(
         (prev, next),
-        (Some(left), Some(right)) if left.is_ascii_digit() && right.is_ascii_digit()
+        (Some(left), Some(right)) if is_digit(left) && is_digit(right)
     )
 }
 
-fn phonemize_segments_batch(
-    segments: &[&str],
-    lang: &str,
-    vocab: &HashMap<char, i64>,
-    espeak: &EspeakConfig,
-) -> Result<Vec<Vec<i64>>, KokoroError> {
-    let batched_input = segments.join("\n");
-    let output = run_espeak(&batched_input, lang, espeak)?;
-    let lines: Vec<&str> = output.lines().collect();
-
-    // espeak-ng should emit one line per input line for stdin mode.
-    // If this assumption breaks, fall back to per-segment invocation.
-    if lines.len() != segments.len() {
-        return segments
-            .iter()
-            .map(|segment| {
-                let output = run_espeak(segment, lang, espeak)?;
-                Ok(ipa_to_ids(&output, vocab))
-            })
-            .collect();
-    }
-
-    Ok(lines.iter().map(|line| ipa_to_ids(line, vocab)).collect())
+/// Build an espeak-ng command that reads text on stdin and prints IPA.
+pub(crate) fn espeak_command(lang: &str, espeak: &EspeakConfig) -> Command {
+    let mut cmd = espeak_line_command(lang, espeak);
+    cmd.arg("--stdin");
+    cmd
 }
 
-fn run_espeak(input: &str, lang: &str, espeak: &EspeakConfig) -> Result<String, KokoroError> {
+/// espeak-ng reading stdin line by line.
+///
+/// Without `--stdin` espeak-ng answers each input line as soon as it arrives
+/// instead of buffering everything until EOF, which is what a long-lived
+/// process needs.
+pub(crate) fn espeak_line_command(lang: &str, espeak: &EspeakConfig) -> Command {
     let bin = espeak
         .bin_path
         .as_deref()
         .map(|p| p.as_os_str().to_owned())
         .unwrap_or_else(|| std::ffi::OsString::from("espeak-ng"));
     let mut cmd = Command::new(&bin);
-    cmd.args(["--ipa", "--stdin", "-q", "-v", lang]);
+    cmd.args(["--ipa", "-q", "-v", lang]);
     if let Some(data_path) = espeak.data_path.as_deref() {
         cmd.arg("--path").arg(data_path);
     }
@@ -204,18 +998,24 @@ fn run_espeak(input: &str, lang: &str, espeak: &EspeakConfig) -> Result<String,
     if let Some(bin_dir) = espeak.bin_path.as_deref().and_then(|p| p.parent()) {
         cmd.env("LD_LIBRARY_PATH", bin_dir);
     }
-    let mut child = cmd
+    cmd
+}
+
+pub(crate) fn spawn_error(e: std::io::Error) -> KokoroError {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        KokoroError::EspeakNotFound
+    } else {
+        KokoroError::Io(e)
+    }
+}
+
+fn run_espeak(input: &str, lang: &str, espeak: &EspeakConfig) -> Result<String, KokoroError> {
+    let mut child = espeak_command(lang, espeak)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
-        .map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                KokoroError::EspeakNotFound
-            } else {
-                KokoroError::Io(e)
-            }
-        })?;
+        .map_err(spawn_error)?;
 
     if let Some(mut stdin) = child.stdin.take() {
         // espeak-ng treats stdin as line-oriented input. Without a final line terminator,
@@ -248,39 +1048,66 @@ fn canonicalize_espeak_stdin_payload(input: &str) -> Cow<'_, str> {
     }
 }
 
-fn ipa_to_ids(ipa: &str, vocab: &HashMap<char, i64>) -> Vec<i64> {
-    let mut ids = Vec::new();
+/// Tokens and IPA for one whitespace-separated word of espeak-ng output.
+#[derive(Debug, Default)]
+struct IpaWord {
+    /// Token IDs, including the separator that follows the word.
+    ids: Vec<i64>,
+    phonemes: String,
+}
+
+/// Convert espeak-ng IPA output to token IDs grouped by word.
+///
+/// Concatenating the groups gives the full token sequence: characters not in
+/// the vocab are dropped and word separators are kept as tokens.
+fn ipa_to_words(ipa: &str, vocab: &HashMap<char, i64>) -> Vec<IpaWord> {
+    let mut words = Vec::new();
+    let mut current = IpaWord::default();
     for line in ipa.lines() {
         let line = line.trim();
         if line.is_empty() {
             continue;
         }
+        let mut at_word_start = true;
         for ch in line.chars() {
             if ch == '_' {
                 continue;
             }
+            if ch.is_whitespace() {
+                at_word_start = true;
+            } else if at_word_start {
+                if !current.phonemes.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+                at_word_start = false;
+            }
+            if !ch.is_whitespace() {
+                current.phonemes.push(ch);
+            }
             if let Some(&id) = vocab.get(&ch) {
-                ids.push(id);
+                current.ids.push(id);
             }
         }
     }
-    ids
+    if !current.phonemes.is_empty() || !current.ids.is_empty() {
+        words.push(current);
+    }
+    words
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        canonicalize_espeak_stdin_payload, phonemize, run_espeak, split_text_parts, EspeakConfig,
-        TextPart,
+        assemble_phonemized, canonicalize_espeak_stdin_payload, completed_sentences_len, phonemize,
+        phonemize_ipa, run_espeak, split_sentences, split_text_chunks, split_text_parts,
+        split_text_parts_with_spans, EspeakConfig, EspeakPhonemizer, EspeakWorker, KokoroError,
+        Phonemizer, TextPart,
     };
     use crate::engines::kokoro::vocab::hardcoded_vocab;
     use std::process::Command;
 
     fn espeak_available() -> bool {
-        Command::new("espeak-ng")
-            .arg("--version")
-            .output()
-            .is_ok()
+        Command::new("espeak-ng").arg("--version").output().is_ok()
     }
 
     #[test]
@@ -324,6 +1151,316 @@ mod tests {
         );
     }
 
+    #[test]
+    fn splits_inline_pronunciations() {
+        let text = "Say [Kokoro](/kˈOkəɹO/), not [koko] (/x/). Use [Node.js](/nˈOd ʤˈAɛs/).";
+        let parts = split_text_parts_with_spans(text, "en-us");
+        assert_eq!(parts[0].0, TextPart::Text("Say".to_string()));
+        assert_eq!(parts[1].0, TextPart::Ipa("kˈOkəɹO".to_string()));
+        assert_eq!(&text[parts[1].1.clone()], "Kokoro");
+        assert_eq!(parts[2].0, TextPart::Punct(','));
+        assert_eq!(parts[3].0, TextPart::Text("not [koko]".to_string()));
+        assert!(parts.contains(&(
+            TextPart::Ipa("nˈOd ʤˈAɛs".to_string()),
+            text.find("Node.js").unwrap()..text.find("](/n").unwrap()
+        )));
+
+        let sentences: Vec<&str> = split_sentences(text)
+            .into_iter()
+            .map(|span| &text[span])
+            .collect();
+        assert_eq!(sentences.len(), 2);
+        assert!(sentences[1].starts_with("Use [Node.js]"));
+    }
+
+    #[test]
+    fn splits_sentences_with_trailing_closers() {
+        let text = "He said \"Hi!\" Then left. Version 2.0 shipped\nDone";
+        let sentences: Vec<&str> = split_sentences(text)
+            .into_iter()
+            .map(|span| &text[span])
+            .collect();
+        assert_eq!(
+            sentences,
+            vec![
+                "He said \"Hi!\"",
+                "Then left.",
+                "Version 2.0 shipped",
+                "Done"
+            ]
+        );
+    }
+
+    #[test]
+    fn chunks_text_at_sentences_then_clauses_then_words() {
+        let chunks = |text: &'static str, max_chars| -> Vec<&'static str> {
+            split_text_chunks(text, "en-us", max_chars)
+                .into_iter()
+                .map(|span| &text[span])
+                .collect()
+        };
+        assert_eq!(
+            chunks("One. Two. Three is longer.", 16),
+            vec!["One. Two.", "Three is longer."]
+        );
+        assert_eq!(
+            chunks("First part, second part; the rest of it.", 20),
+            vec!["First part,", "second part;", "the rest of it."]
+        );
+        assert_eq!(
+            chunks("Dr. Smith paid 1,500 dollars for it.", 16),
+            vec!["Dr. Smith paid", "1,500 dollars", "for it."]
+        );
+        assert_eq!(
+            chunks("Say [Kokoro](/kˈO kəɹO/) now.", 8),
+            vec!["Say", "[Kokoro](/kˈO kəɹO/)", "now."]
+        );
+        assert_eq!(
+            chunks("こんにちは世界。", 3),
+            vec!["こんに", "ちは世", "界。"]
+        );
+    }
+
+    #[test]
+    fn maps_cjk_punctuation_to_boundaries() {
+        let parts = split_text_parts("「こんにちは」、世界。价格是１．５元，好吗？");
+        assert_eq!(
+            parts,
+            vec![
+                TextPart::Punct('\u{201c}'),
+                TextPart::Text("こんにちは".to_string()),
+                TextPart::Punct('\u{201d}'),
+                TextPart::Punct(','),
+                TextPart::Text("世界".to_string()),
+                TextPart::Punct('.'),
+                TextPart::Text("价格是１．５元".to_string()),
+                TextPart::Punct(','),
+                TextPart::Text("好吗".to_string()),
+                TextPart::Punct('?'),
+            ]
+        );
+
+        let text = "「はい。」と言った。次は？";
+        let sentences: Vec<&str> = split_sentences(text)
+            .into_iter()
+            .map(|span| &text[span])
+            .collect();
+        assert_eq!(sentences, vec!["「はい。」", "と言った。", "次は？"]);
+    }
+
+    fn completed_sentences_len_en(text: &str) -> usize {
+        completed_sentences_len(text, "en-us")
+    }
+
+    #[test]
+    fn completed_sentences_wait_for_following_input() {
+        assert_eq!(completed_sentences_len_en("Hello there"), 0);
+        assert_eq!(completed_sentences_len_en("It costs 2."), 0);
+        assert_eq!(completed_sentences_len_en("It costs 2.5"), 0);
+        assert_eq!(completed_sentences_len_en("Done. Next"), "Done.".len());
+        assert_eq!(completed_sentences_len_en("Really?! Yes"), "Really?!".len());
+        assert_eq!(completed_sentences_len_en("Ask Dr. "), 0);
+        assert_eq!(
+            completed_sentences_len_en("Ask Dr. Smith. He"),
+            "Ask Dr. Smith.".len()
+        );
+    }
+
+    #[test]
+    fn aligns_tokens_with_source_words() {
+        let vocab = hardcoded_vocab();
+        let text = "Hi, big  cat.";
+        let parts = split_text_parts_with_spans(text, "en-us");
+        let ipa = vec!["hˈI".to_string(), "bˈɪɡ kˈæt".to_string()];
+        let result = assemble_phonemized(text, parts, &ipa, &vocab);
+
+        let words: Vec<(&str, &str)> = result
+            .words
+            .iter()
+            .map(|w| (&text[w.text_span.clone()], w.phonemes.as_str()))
+            .collect();
+        assert_eq!(
+            words,
+            vec![("Hi,", "hˈI"), ("big", "bˈɪɡ"), ("cat.", "kˈæt")]
+        );
+
+        // "hˈI" + ","  |  "bˈɪɡ" + " "  |  "kˈæt" + "."
+        let ranges: Vec<_> = result.words.iter().map(|w| w.tokens.clone()).collect();
+        assert_eq!(ranges, vec![0..4, 4..9, 9..14]);
+        assert_eq!(result.ids.len(), 14);
+    }
+
+    /// Records its inputs and answers every segment with a fixed word.
+    #[derive(Default)]
+    struct StubPhonemizer {
+        calls: Vec<(String, String)>,
+    }
+
+    impl Phonemizer for StubPhonemizer {
+        fn phonemize(&mut self, text: &str, lang: &str) -> Result<String, KokoroError> {
+            self.calls.push((text.to_string(), lang.to_string()));
+            Ok("hˈI".to_string())
+        }
+    }
+
+    #[test]
+    fn phonemizes_through_custom_phonemizer() {
+        let vocab = hardcoded_vocab();
+        let mut stub = StubPhonemizer::default();
+        let ids = phonemize("Hi, you", "fr", &vocab, &mut stub).unwrap();
+
+        assert_eq!(
+            stub.calls,
+            vec![
+                ("Hi".to_string(), "fr".to_string()),
+                ("you".to_string(), "fr".to_string())
+            ]
+        );
+        let expected: Vec<i64> = "hˈI,hˈI"
+            .chars()
+            .filter_map(|ch| vocab.get(&ch).copied())
+            .collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn maps_raw_ipa_without_espeak() {
+        let vocab = hardcoded_vocab();
+        let ipa = "kˈOkəɹO  ɪz.";
+        let result = phonemize_ipa(ipa, &vocab);
+
+        let expected: Vec<i64> = "kˈOkəɹO ɪz."
+            .chars()
+            .filter_map(|ch| vocab.get(&ch).copied())
+            .collect();
+        assert_eq!(result.ids, expected);
+        let words: Vec<_> = result
+            .words
+            .iter()
+            .map(|w| &ipa[w.text_span.clone()])
+            .collect();
+        assert_eq!(words, vec!["kˈOkəɹO", "ɪz."]);
+    }
+
+    #[test]
+    fn distributes_extra_ipa_words_over_source_words() {
+        let vocab = hardcoded_vocab();
+        let text = "66 roads";
+        let parts = split_text_parts_with_spans(text, "en-us");
+        let ipa = vec!["sˈɪksti sˈɪks ɹˈoʊdz".to_string()];
+        let result = assemble_phonemized(text, parts, &ipa, &vocab);
+
+        let phonemes: Vec<&str> = result.words.iter().map(|w| w.phonemes.as_str()).collect();
+        assert_eq!(phonemes, vec!["sˈɪksti sˈɪks", "ɹˈoʊdz"]);
+        assert_eq!(result.words[1].tokens.end, result.ids.len());
+    }
+
+    /// Executable stand-in for espeak-ng, deleted together with its crash
+    /// marker on drop.
+    #[cfg(unix)]
+    struct FakeEspeak(EspeakConfig);
+
+    #[cfg(unix)]
+    impl Drop for FakeEspeak {
+        fn drop(&mut self) {
+            if let Some(path) = self.0.bin_path.as_deref() {
+                let _ = std::fs::remove_file(path.with_extension("crashed"));
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    /// Write an executable stand-in for espeak-ng that answers each stdin
+    /// line with `[line]`. The line `crash` makes the first process exit and
+    /// `hang` stalls it.
+    #[cfg(unix)]
+    fn fake_espeak(name: &str) -> FakeEspeak {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path =
+            std::env::temp_dir().join(format!("tts-rs-fake-espeak-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_file(path.with_extension("crashed"));
+        std::fs::write(
+            &path,
+            r#"#!/bin/sh
+while IFS= read -r line; do
+  case "$line" in
+    crash) [ -e "$0.crashed" ] || { touch "$0.crashed"; exit 1; } ;;
+    hang) sleep 2 ;;
+  esac
+  echo "[$line]"
+done
+"#,
+        )
+        .unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        FakeEspeak(EspeakConfig {
+            bin_path: Some(path),
+            data_path: None,
+        })
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn worker_frames_consecutive_requests() {
+        let fake = fake_espeak("frames");
+        let mut espeak = EspeakPhonemizer::new(fake.0.clone());
+        let first = espeak
+            .phonemize_batch(&["one", "two\nlines"], "en-us")
+            .unwrap();
+        assert_eq!(first, vec!["[one]", "[two lines]"]);
+        assert_eq!(espeak.phonemize("three", "en-us").unwrap(), "[three]");
+        assert_eq!(espeak.workers.len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn worker_restarts_after_crash() {
+        let fake = fake_espeak("crash");
+        let mut espeak = EspeakPhonemizer::new(fake.0.clone());
+        assert_eq!(espeak.phonemize("hi", "en-us").unwrap(), "[hi]");
+        assert_eq!(espeak.phonemize("crash", "en-us").unwrap(), "[crash]");
+        assert_eq!(espeak.phonemize("hi", "en-us").unwrap(), "[hi]");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn worker_request_times_out() {
+        let fake = fake_espeak("hang");
+        let mut espeak = EspeakPhonemizer::new(fake.0.clone())
+            .with_timeout(std::time::Duration::from_millis(300));
+        assert_eq!(espeak.phonemize("hi", "en-us").unwrap(), "[hi]");
+
+        let started = std::time::Instant::now();
+        let err = espeak.phonemize("hang", "en-us").unwrap_err();
+        assert!(matches!(err, KokoroError::PhonemizerFailed(_)));
+        assert!(started.elapsed() < std::time::Duration::from_secs(2));
+        assert_eq!(espeak.phonemize("hi", "en-us").unwrap(), "[hi]");
+    }
+
+    #[test]
+    fn worker_answers_real_espeak_line_by_line() {
+        if !espeak_available() {
+            return;
+        }
+
+        let cfg = EspeakConfig::default();
+        let mut worker =
+            EspeakWorker::spawn("en-us", &cfg, std::time::Duration::from_secs(5)).unwrap();
+        let lines = worker
+            .request(&["Hello there.", "America"])
+            .expect("espeak-ng should answer each line without waiting for EOF");
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[1].trim(),
+            run_espeak("America", "en-us", &cfg).unwrap().trim()
+        );
+        assert_eq!(
+            worker.request(&["America"]).unwrap(),
+            vec![lines[1].clone()]
+        );
+    }
+
     #[test]
     fn appends_trailing_newline_for_espeak_stdin() {
         assert_eq!(canonicalize_espeak_stdin_payload("America"), "America\n");
@@ -341,10 +1478,8 @@ mod tests {
         }
 
         let cfg = EspeakConfig::default();
-        let without_newline =
-            run_espeak("America", "en-us", &cfg).expect("espeak should succeed");
-        let with_newline =
-            run_espeak("America\n", "en-us", &cfg).expect("espeak should succeed");
+        let without_newline = run_espeak("America", "en-us", &cfg).expect("espeak should succeed");
+        let with_newline = run_espeak("America\n", "en-us", &cfg).expect("espeak should succeed");
         assert_eq!(
             without_newline.trim(),
             with_newline.trim(),
@@ -359,9 +1494,8 @@ mod tests {
         }
 
         let vocab = hardcoded_vocab();
-        let cfg = EspeakConfig::default();
-        let ids =
-            phonemize("America", "en-us", &vocab, &cfg).expect("phonemize should succeed");
+        let ids = phonemize("America", "en-us", &vocab, &mut EspeakPhonemizer::default())
+            .expect("phonemize should succeed");
         let schwa_id = *vocab
             .get(&'ə')
             .expect("hardcoded vocab should include schwa");
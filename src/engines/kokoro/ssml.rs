@@ -0,0 +1,788 @@
+use std::ops::Range;
+
+use super::g2p::date_words;
+use super::model::{KokoroError, KokoroModel, SAMPLE_RATE};
+use super::normalize::set_language;
+use super::phonemizer::{split_text_parts_with_spans, voice_lang, Phonemizer, TextPart};
+use crate::{MarkTimestamp, SynthesisResult};
+
+/// Smallest and largest `<prosody rate>` multiplier, after nesting. The base
+/// speed it multiplies is used as given, as for plain text.
+const MIN_RATE: f32 = 0.5;
+const MAX_RATE: f32 = 2.0;
+
+/// An SSML document compiled into Kokoro synthesis segments.
+///
+/// Supported elements:
+/// - `<speak>`, `<p>`, `<s>` (paragraphs and sentences end with a full stop)
+/// - `<break time="500ms"/>` / `<break strength="strong"/>`
+/// - `<prosody rate="slow|fast|80%|1.2">`
+/// - `<voice name="bf_emma">`
+/// - `<say-as interpret-as="characters|spell-out|cardinal|date" format="mdy">`
+/// - `<phoneme alphabet="ipa" ph="...">` (IPA goes straight to the vocab)
+/// - `<sub alias="...">`
+/// - `<mark name="..."/>` (reported in [`SynthesisResult::marks`])
+///
+/// Other elements are ignored but their text content is spoken.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SsmlDocument {
+    text: String,
+    segments: Vec<SsmlSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SsmlSegment {
+    Speech(Speech),
+    Break { secs: f32 },
+    Mark(String),
+}
+
+/// A run of text spoken with one voice and rate.
+#[derive(Debug, Clone, PartialEq)]
+struct Speech {
+    parts: Vec<(TextPart, Range<usize>)>,
+    voice: Option<String>,
+    /// espeak-ng language the text is read in
+    lang: &'static str,
+    rate: f32,
+    /// Marks inside the run, with the byte offset in the spoken text they precede.
+    marks: Vec<(String, usize)>,
+}
+
+impl SsmlDocument {
+    /// Parse an SSML string. A missing `<speak>` root is tolerated.
+    ///
+    /// Only the official voice prefixes
+    /// ([`DEFAULT_VOICE_LANGUAGES`](super::phonemizer::DEFAULT_VOICE_LANGUAGES))
+    /// are known here: abbreviations follow the language of a `<voice>` with
+    /// such a prefix and English rules everywhere else, including outside
+    /// `<voice>`. Configured voice languages, voice metadata and
+    /// [`KokoroInferenceParams::language`](super::KokoroInferenceParams::language)
+    /// are applied by
+    /// [`KokoroEngine::synthesize_ssml`](super::KokoroEngine::synthesize_ssml),
+    /// which parses the markup itself, so the texts of the two can differ.
+    pub fn parse(ssml: &str) -> Result<Self, KokoroError> {
+        Self::parse_with(ssml, &|voice| voice.map_or("", voice_lang))
+    }
+
+    /// Parse an SSML string, reading the text of each voice in the language
+    /// `lang_of` returns for it. `lang_of` gets the voice named in the markup,
+    /// or `None` outside `<voice>`.
+    pub(crate) fn parse_with(
+        ssml: &str,
+        lang_of: &dyn Fn(Option<&str>) -> &'static str,
+    ) -> Result<Self, KokoroError> {
+        let mut compiler = Compiler::new(lang_of);
+        for event in tokenize(ssml)? {
+            compiler.handle(event)?;
+        }
+        Ok(compiler.finish())
+    }
+
+    /// The text that will be spoken, with markup removed and substitutions
+    /// applied. Word timestamps of the synthesis result index into this string.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Synthesize a parsed document segment by segment.
+pub(crate) fn synthesize_document(
+    model: &mut KokoroModel,
+    doc: &SsmlDocument,
+    voice_name: &str,
+    speed: f32,
+    style_idx_override: Option<usize>,
+    phonemizer: &mut dyn Phonemizer,
+) -> Result<SynthesisResult, KokoroError> {
+    let mut output = SynthesisResult::new(Vec::new(), SAMPLE_RATE);
+
+    for segment in &doc.segments {
+        let offset_secs = output.duration_secs();
+        match segment {
+            SsmlSegment::Speech(speech) => {
+                let voice = speech.voice.as_deref().unwrap_or(voice_name);
+                let speed = speed * speech.rate.clamp(MIN_RATE, MAX_RATE);
+                let mut parts = speech.parts.clone();
+                if speech.lang != model.voice_lang(voice) {
+                    set_language(std::slice::from_mut(&mut parts), speech.lang);
+                }
+                let result = model.synthesize_parts(
+                    &doc.text,
+                    parts,
+                    voice,
+                    speed,
+                    style_idx_override,
+                    phonemizer,
+                )?;
+
+                for (name, at) in &speech.marks {
+                    let secs = result
+                        .words
+                        .iter()
+                        .find(|word| word.text_span.start >= *at)
+                        .map_or(result.duration_secs(), |word| word.start_secs);
+                    output.marks.push(MarkTimestamp {
+                        name: name.clone(),
+                        time_secs: offset_secs + secs,
+                    });
+                }
+                output
+                    .words
+                    .extend(result.words.into_iter().map(|mut word| {
+                        word.start_secs += offset_secs;
+                        word.end_secs += offset_secs;
+                        word
+                    }));
+                output
+                    .phonemes
+                    .extend(result.phonemes.into_iter().map(|mut phoneme| {
+                        phoneme.start_secs += offset_secs;
+                        phoneme.end_secs += offset_secs;
+                        phoneme
+                    }));
+                output.samples.extend(result.samples);
+                if output.language.is_none() {
+                    output.language = Some(speech.lang.to_string());
+                }
+            }
+            SsmlSegment::Break { secs } => {
+                let n = (secs * SAMPLE_RATE as f32).round() as usize;
+                output.samples.resize(output.samples.len() + n, 0.0);
+            }
+            SsmlSegment::Mark(name) => output.marks.push(MarkTimestamp {
+                name: name.clone(),
+                time_secs: offset_secs,
+            }),
+        }
+    }
+
+    Ok(output)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Start {
+        name: String,
+        attrs: Vec<(String, String)>,
+        empty: bool,
+    },
+    End(String),
+    Text(String),
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+/// Split SSML into tags and (entity-decoded) text. Comments, processing
+/// instructions and doctypes are skipped.
+fn tokenize(ssml: &str) -> Result<Vec<Event>, KokoroError> {
+    let mut events = Vec::new();
+    let mut rest = ssml;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("<!--") {
+            let end = after
+                .find("-->")
+                .ok_or_else(|| ssml_error("unterminated comment"))?;
+            rest = &after[end + 3..];
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            let end = rest
+                .find('>')
+                .ok_or_else(|| ssml_error("unterminated declaration"))?;
+            rest = &rest[end + 1..];
+        } else if let Some(after) = rest.strip_prefix("</") {
+            let end = after
+                .find('>')
+                .ok_or_else(|| ssml_error("unterminated closing tag"))?;
+            events.push(Event::End(after[..end].trim().to_string()));
+            rest = &after[end + 1..];
+        } else if let Some(after) = rest.strip_prefix('<') {
+            let (event, consumed) = parse_start_tag(after)?;
+            events.push(event);
+            rest = &after[consumed..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            events.push(Event::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+
+    Ok(events)
+}
+
+/// Parse the inside of a start tag (after `<`). Returns the event and the
+/// number of bytes consumed, including the closing `>`.
+fn parse_start_tag(input: &str) -> Result<(Event, usize), KokoroError> {
+    let name_len = input
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .ok_or_else(|| ssml_error("unterminated tag"))?;
+    let name = input[..name_len].to_string();
+    if name.is_empty() {
+        return Err(ssml_error("empty tag name"));
+    }
+
+    let mut attrs = Vec::new();
+    let mut pos = name_len;
+    loop {
+        let rest = &input[pos..];
+        let trimmed = rest.trim_start();
+        pos += rest.len() - trimmed.len();
+
+        if trimmed.starts_with("/>") {
+            let event = Event::Start {
+                name,
+                attrs,
+                empty: true,
+            };
+            return Ok((event, pos + 2));
+        }
+        if trimmed.starts_with('>') {
+            let event = Event::Start {
+                name,
+                attrs,
+                empty: false,
+            };
+            return Ok((event, pos + 1));
+        }
+
+        let eq = trimmed
+            .find('=')
+            .ok_or_else(|| ssml_error(&format!("malformed attribute in <{name}>")))?;
+        let key = trimmed[..eq].trim().to_string();
+        let value_start = trimmed[eq + 1..].trim_start();
+        let quote = value_start
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')
+            .ok_or_else(|| ssml_error(&format!("unquoted attribute '{key}' in <{name}>")))?;
+        let value_len = value_start[1..]
+            .find(quote)
+            .ok_or_else(|| ssml_error(&format!("unterminated attribute '{key}' in <{name}>")))?;
+        attrs.push((key, decode_entities(&value_start[1..1 + value_len])));
+
+        let consumed_value = trimmed.len() - value_start.len() + value_len + 2;
+        pos += consumed_value;
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let after = &rest[amp..];
+        let decoded = after.find(';').and_then(|semi| {
+            let entity = &after[1..semi];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            ch.map(|ch| (ch, semi + 1))
+        });
+        match decoded {
+            Some((ch, len)) => {
+                out.push(ch);
+                rest = &after[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &after[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn ssml_error(message: &str) -> KokoroError {
+    KokoroError::Ssml(message.to_string())
+}
+
+#[derive(Debug, Clone)]
+struct Style {
+    voice: Option<String>,
+    rate: f32,
+}
+
+/// Element whose text content is replaced rather than spoken as-is.
+#[derive(Debug)]
+enum Capture {
+    SayAs {
+        interpret_as: String,
+        format: Option<String>,
+        text: String,
+    },
+    Phoneme {
+        ph: String,
+        text: String,
+    },
+    Sub {
+        alias: String,
+    },
+}
+
+struct Compiler<'a> {
+    /// Language of the text of a voice (`None` for the default voice)
+    lang_of: &'a dyn Fn(Option<&str>) -> &'static str,
+    text: String,
+    segments: Vec<SsmlSegment>,
+    current: Option<Speech>,
+    /// Start (in `text`) of text not yet split into parts.
+    pending_start: Option<usize>,
+    styles: Vec<Style>,
+    capture: Option<Capture>,
+}
+
+impl<'a> Compiler<'a> {
+    fn new(lang_of: &'a dyn Fn(Option<&str>) -> &'static str) -> Self {
+        Self {
+            lang_of,
+            text: String::new(),
+            segments: Vec::new(),
+            current: None,
+            pending_start: None,
+            styles: Vec::new(),
+            capture: None,
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.styles.last().cloned().unwrap_or(Style {
+            voice: None,
+            rate: 1.0,
+        })
+    }
+
+    fn handle(&mut self, event: Event) -> Result<(), KokoroError> {
+        match event {
+            Event::Text(text) => {
+                // Line breaks in markup are layout, not sentence boundaries.
+                let text = text.replace(['\n', '\r', '\t'], " ");
+                match self.capture.as_mut() {
+                    Some(Capture::SayAs { text: buf, .. } | Capture::Phoneme { text: buf, .. }) => {
+                        buf.push_str(&text)
+                    }
+                    Some(Capture::Sub { .. }) => {}
+                    None => self.push_text(&text),
+                }
+                Ok(())
+            }
+            Event::Start { name, attrs, empty } => {
+                self.start_element(&name, &attrs)?;
+                if empty {
+                    self.end_element(&name);
+                }
+                Ok(())
+            }
+            Event::End(name) => {
+                self.end_element(&name);
+                Ok(())
+            }
+        }
+    }
+
+    fn start_element(&mut self, name: &str, attrs: &[(String, String)]) -> Result<(), KokoroError> {
+        let attr = |key| attr(attrs, key);
+        match name {
+            "voice" => {
+                let mut style = self.style();
+                if let Some(voice) = attr("name") {
+                    style.voice = Some(voice.to_string());
+                }
+                self.flush_speech();
+                self.styles.push(style);
+            }
+            "prosody" => {
+                let mut style = self.style();
+                if let Some(rate) = attr("rate") {
+                    style.rate *= parse_rate(rate)?;
+                }
+                self.flush_speech();
+                self.styles.push(style);
+            }
+            "break" => {
+                let secs = match (attr("time"), attr("strength")) {
+                    (Some(time), _) => parse_duration(time)?,
+                    (None, Some(strength)) => strength_secs(strength)?,
+                    (None, None) => strength_secs("medium")?,
+                };
+                self.flush_speech();
+                if secs > 0.0 {
+                    self.segments.push(SsmlSegment::Break { secs });
+                }
+            }
+            "mark" => {
+                let name = attr("name")
+                    .ok_or_else(|| ssml_error("<mark> requires a name"))?
+                    .to_string();
+                match self.current.as_mut() {
+                    Some(speech) => speech.marks.push((name, self.text.len())),
+                    None => self.segments.push(SsmlSegment::Mark(name)),
+                }
+            }
+            "say-as" => {
+                self.capture = Some(Capture::SayAs {
+                    interpret_as: attr("interpret-as").unwrap_or_default().to_string(),
+                    format: attr("format").map(str::to_string),
+                    text: String::new(),
+                });
+            }
+            "phoneme" => {
+                if let Some(alphabet) = attr("alphabet").filter(|a| *a != "ipa") {
+                    log::warn!("Unsupported phoneme alphabet '{alphabet}', treating as IPA");
+                }
+                let ph = attr("ph").ok_or_else(|| ssml_error("<phoneme> requires ph"))?;
+                self.capture = Some(Capture::Phoneme {
+                    ph: ph.to_string(),
+                    text: String::new(),
+                });
+            }
+            "sub" => {
+                let alias = attr("alias").ok_or_else(|| ssml_error("<sub> requires alias"))?;
+                self.capture = Some(Capture::Sub {
+                    alias: alias.to_string(),
+                });
+            }
+            _ => log::debug!("Ignoring SSML element <{name}>"),
+        }
+        Ok(())
+    }
+
+    fn end_element(&mut self, name: &str) {
+        match name {
+            "voice" | "prosody" => {
+                self.flush_speech();
+                self.styles.pop();
+            }
+            "p" | "s" => {
+                self.commit_pending();
+                if let Some(speech) = self.current.as_mut() {
+                    let ends_with_punct =
+                        matches!(speech.parts.last(), Some((TextPart::Punct(_), _)) | None);
+                    if !ends_with_punct {
+                        let at = self.text.len();
+                        speech.parts.push((TextPart::Punct('.'), at..at));
+                    }
+                }
+            }
+            "say-as" | "phoneme" | "sub" => match self.capture.take() {
+                Some(Capture::SayAs {
+                    interpret_as,
+                    format,
+                    text,
+                }) => {
+                    let british = self.speech().lang == "en-gb";
+                    let spoken = interpret_say_as(&interpret_as, format.as_deref(), &text, british);
+                    self.push_text(&spoken);
+                }
+                Some(Capture::Phoneme { ph, text }) => self.push_ipa(&text, &ph),
+                Some(Capture::Sub { alias }) => self.push_text(&alias),
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn speech(&mut self) -> &mut Speech {
+        let style = self.style();
+        let lang_of = self.lang_of;
+        self.current.get_or_insert_with(|| Speech {
+            parts: Vec::new(),
+            lang: lang_of(style.voice.as_deref()),
+            voice: style.voice,
+            rate: style.rate,
+            marks: Vec::new(),
+        })
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.speech();
+        self.pending_start.get_or_insert(self.text.len());
+        self.text.push_str(text);
+    }
+
+    fn push_ipa(&mut self, display: &str, ipa: &str) {
+        self.commit_pending();
+        if !self.text.is_empty() && !self.text.ends_with(char::is_whitespace) {
+            self.text.push(' ');
+        }
+        let display = display.trim();
+        let start = self.text.len();
+        self.text.push_str(display);
+        let span = start..self.text.len();
+        self.text.push(' ');
+        self.speech()
+            .parts
+            .push((TextPart::Ipa(ipa.to_string()), span));
+    }
+
+    /// Split pending text into parts and add them to the current speech.
+    fn commit_pending(&mut self) {
+        let Some(start) = self.pending_start.take() else {
+            return;
+        };
+        let lang = self.speech().lang;
+        let parts = split_text_parts_with_spans(&self.text[start..], lang)
+            .into_iter()
+            .map(|(part, span)| (part, start + span.start..start + span.end));
+        self.speech().parts.extend(parts);
+    }
+
+    fn flush_speech(&mut self) {
+        self.commit_pending();
+        if let Some(speech) = self.current.take() {
+            if speech.parts.is_empty() {
+                self.segments.extend(
+                    speech
+                        .marks
+                        .into_iter()
+                        .map(|(name, _)| SsmlSegment::Mark(name)),
+                );
+            } else {
+                self.segments.push(SsmlSegment::Speech(speech));
+            }
+        }
+    }
+
+    fn finish(mut self) -> SsmlDocument {
+        self.flush_speech();
+        SsmlDocument {
+            text: self.text,
+            segments: self.segments,
+        }
+    }
+}
+
+/// Parse a `<prosody rate>` value into a speed multiplier.
+fn parse_rate(rate: &str) -> Result<f32, KokoroError> {
+    let rate = rate.trim();
+    let value = match rate {
+        "x-slow" => 0.5,
+        "slow" => 0.75,
+        "medium" | "default" => 1.0,
+        "fast" => 1.25,
+        "x-fast" => 1.5,
+        _ => match rate.strip_suffix('%') {
+            // "+20%" / "-10%" are relative changes, "80%" is absolute.
+            Some(pct) if pct.starts_with(['+', '-']) => pct.parse::<f32>().map(|p| 1.0 + p / 100.0),
+            Some(pct) => pct.parse::<f32>().map(|p| p / 100.0),
+            None => rate.parse::<f32>(),
+        }
+        .map_err(|_| ssml_error(&format!("invalid prosody rate '{rate}'")))?,
+    };
+    if value <= 0.0 {
+        return Err(ssml_error(&format!("invalid prosody rate '{rate}'")));
+    }
+    Ok(value)
+}
+
+/// Parse a `<break time>` value (`"500ms"`, `"1.5s"`) into seconds.
+fn parse_duration(time: &str) -> Result<f32, KokoroError> {
+    let time = time.trim();
+    let parsed = if let Some(ms) = time.strip_suffix("ms") {
+        ms.trim().parse::<f32>().map(|ms| ms / 1000.0)
+    } else if let Some(s) = time.strip_suffix('s') {
+        s.trim().parse::<f32>()
+    } else {
+        time.parse::<f32>().map(|ms| ms / 1000.0)
+    };
+    parsed
+        .ok()
+        .filter(|secs| *secs >= 0.0)
+        .ok_or_else(|| ssml_error(&format!("invalid break time '{time}'")))
+}
+
+fn strength_secs(strength: &str) -> Result<f32, KokoroError> {
+    Ok(match strength {
+        "none" => 0.0,
+        "x-weak" => 0.1,
+        "weak" => 0.25,
+        "medium" => 0.4,
+        "strong" => 0.75,
+        "x-strong" => 1.2,
+        _ => return Err(ssml_error(&format!("invalid break strength '{strength}'"))),
+    })
+}
+
+/// Rewrite `<say-as>` content into text the phonemizer reads correctly.
+/// Dates read in British order when `british` is set.
+fn interpret_say_as(interpret_as: &str, format: Option<&str>, text: &str, british: bool) -> String {
+    let text = text.trim();
+    match interpret_as {
+        "characters" | "spell-out" => text
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .join(" "),
+        "cardinal" | "number" => text
+            .chars()
+            .filter(|c| !matches!(c, ',' | ' ' | '_'))
+            .collect(),
+        "date" => format_date(text, format, british).unwrap_or_else(|| text.to_string()),
+        _ => text.to_string(),
+    }
+}
+
+/// Spell out a numeric date (`2026-10-17`, `10/17/2026`, ...) the way text
+/// normalization reads dates. `format` follows SSML (`ymd`, `mdy`, `dmy`,
+/// ...).
+fn format_date(text: &str, format: Option<&str>, british: bool) -> Option<String> {
+    let fields: Vec<&str> = text.split(['-', '/', '.']).map(str::trim).collect();
+    let format = format.unwrap_or(if fields[0].len() == 4 { "ymd" } else { "mdy" });
+    if format.len() != fields.len() {
+        return None;
+    }
+
+    let (mut year, mut month, mut day) = (None, None, None);
+    for (key, value) in format.chars().zip(fields) {
+        match key {
+            'y' => year = Some(value),
+            'm' => month = Some(value.parse().ok()?),
+            'd' => day = Some(value.parse().ok()?),
+            _ => return None,
+        }
+    }
+    date_words(month?, day, year, british)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SsmlDocument, SsmlSegment, TextPart};
+
+    fn speech_texts(doc: &SsmlDocument) -> Vec<Vec<String>> {
+        doc.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                SsmlSegment::Speech(speech) => Some(
+                    speech
+                        .parts
+                        .iter()
+                        .map(|(part, _)| match part {
+                            TextPart::Text(text) | TextPart::Foreign(text, _) => text.clone(),
+                            TextPart::Punct(ch) => ch.to_string(),
+                            TextPart::Ipa(ipa) => format!("/{ipa}/"),
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compiles_breaks_voices_and_prosody() {
+        let doc = SsmlDocument::parse(
+            r#"<?xml version="1.0"?>
+            <speak>Hello there.<break time="300ms"/>
+              <voice name="bf_emma"><prosody rate="slow">Slow &amp; steady.</prosody></voice>
+            </speak>"#,
+        )
+        .unwrap();
+
+        assert_eq!(doc.segments.len(), 3);
+        assert!(
+            matches!(doc.segments[1], SsmlSegment::Break { secs } if (secs - 0.3).abs() < 1e-6)
+        );
+        match &doc.segments[2] {
+            SsmlSegment::Speech(speech) => {
+                assert_eq!(speech.voice.as_deref(), Some("bf_emma"));
+                assert_eq!(speech.rate, 0.75);
+            }
+            other => panic!("expected speech, got {other:?}"),
+        }
+        assert_eq!(
+            speech_texts(&doc),
+            vec![
+                vec!["Hello there".to_string(), ".".to_string()],
+                vec!["Slow & steady".to_string(), ".".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn substitutes_phonemes_aliases_and_say_as() {
+        let doc = SsmlDocument::parse(
+            r#"<speak>Say <phoneme alphabet="ipa" ph="kˈOkəɹO">Kokoro</phoneme> via
+            <sub alias="World Wide Web">WWW</sub>, code <say-as interpret-as="characters">AB1</say-as>
+            on <say-as interpret-as="date" format="dmy">17/10/2026</say-as><mark name="end"/></speak>"#,
+        )
+        .unwrap();
+
+        let texts = speech_texts(&doc);
+        assert_eq!(texts.len(), 1);
+        assert_eq!(
+            texts[0],
+            vec![
+                "Say",
+                "/kˈOkəɹO/",
+                "via World Wide Web",
+                ",",
+                "code A B 1 on October seventeenth",
+                ",",
+                "twenty twenty six"
+            ]
+            .into_iter()
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+        );
+        match &doc.segments[0] {
+            SsmlSegment::Speech(speech) => {
+                assert_eq!(speech.marks, vec![("end".to_string(), doc.text.len())]);
+            }
+            other => panic!("expected speech, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reads_each_voice_in_its_resolved_language() {
+        let ssml = r#"<speak>La Sra. García llegó.<voice name="xx_brand">M. Dupont arrive.</voice></speak>"#;
+        let doc = SsmlDocument::parse_with(ssml, &|voice| match voice {
+            Some("xx_brand") => "fr",
+            _ => "es",
+        })
+        .unwrap();
+        let langs: Vec<&str> = doc
+            .segments
+            .iter()
+            .filter_map(|segment| match segment {
+                SsmlSegment::Speech(speech) => Some(speech.lang),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(langs, ["es", "fr"]);
+        assert_eq!(
+            speech_texts(&doc),
+            vec![
+                vec!["La Sra. García llegó".to_string(), ".".to_string()],
+                vec!["M. Dupont arrive".to_string(), ".".to_string()],
+            ]
+        );
+        // Without the resolver, "Sra." ends a sentence by the English rules.
+        let english = SsmlDocument::parse(ssml).unwrap();
+        assert_eq!(speech_texts(&english)[0][..2], ["La Sra", "."]);
+    }
+
+    #[test]
+    fn rejects_malformed_markup() {
+        assert!(SsmlDocument::parse("<speak><break time=500ms/></speak>").is_err());
+        assert!(SsmlDocument::parse("<speak><!-- open").is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use super::engine::{resolve_voice, KokoroInferenceParams};
+use super::language::intern_language;
+use super::model::{KokoroError, KokoroModel};
+use super::phonemizer::{completed_sentences_len, split_sentences_for, Phonemizer};
+use crate::SynthesisChunk;
+
+/// Push-based synthesis session for text that arrives in pieces.
+///
+/// Feed partial text (e.g. LLM tokens) with [`push`](Self::push). Whenever a
+/// sentence is known to be complete it is synthesized and its audio returned;
+/// the incomplete tail stays buffered until more text arrives or
+/// [`flush`](Self::flush) is called. Sentence boundaries follow the same rules
+/// as the rest of the Kokoro pipeline.
+///
+/// Text spans on the returned chunks are byte ranges into the concatenation of
+/// everything pushed so far.
+///
+/// With [language detection](super::KokoroEngine::set_language_detection)
+/// on, each completed sentence picks its voice and language on its own.
+///
+/// ```rust,no_run
+/// use tts_rs::{SynthesisEngine, engines::kokoro::KokoroEngine};
+/// use std::path::PathBuf;
+///
+/// let mut engine = KokoroEngine::new();
+/// engine.load_model(&PathBuf::from("models/kokoro"))?;
+///
+/// let mut session = engine.text_stream(None)?;
+/// for token in ["Hel", "lo there. ", "How are", " you?"] {
+///     for chunk in session.push(token)? {
+///         println!("{} samples ready", chunk.samples.len());
+///     }
+/// }
+/// let tail = session.flush()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct TextStreamSynthesizer<'a> {
+    model: &'a mut KokoroModel,
+    phonemizer: &'a mut dyn Phonemizer,
+    params: KokoroInferenceParams,
+    /// `params.language` checked against espeak-ng's languages.
+    language: Option<&'static str>,
+    /// Detect the language of each sentence.
+    detect_language: bool,
+    /// Text received but not yet synthesized.
+    buffer: String,
+    /// Byte offset of `buffer` within all text pushed so far.
+    offset: usize,
+}
+
+impl<'a> TextStreamSynthesizer<'a> {
+    pub(crate) fn new(
+        model: &'a mut KokoroModel,
+        phonemizer: &'a mut dyn Phonemizer,
+        params: KokoroInferenceParams,
+        detect_language: bool,
+    ) -> Result<Self, KokoroError> {
+        let language = params
+            .language
+            .as_deref()
+            .map(intern_language)
+            .transpose()?;
+        Ok(Self {
+            model,
+            phonemizer,
+            params,
+            language,
+            detect_language,
+            buffer: String::new(),
+            offset: 0,
+        })
+    }
+
+    /// Append text and synthesize any sentences it completes.
+    ///
+    /// Returns the audio for newly completed sentences, in order. Returns an
+    /// empty `Vec` while the current sentence is still open.
+    pub fn push(&mut self, text: &str) -> Result<Vec<SynthesisChunk>, KokoroError> {
+        self.buffer.push_str(text);
+        let complete = completed_sentences_len(&self.buffer, self.sentence_lang());
+        if complete == 0 {
+            return Ok(Vec::new());
+        }
+        self.synthesize_prefix(complete)
+    }
+
+    /// Synthesize whatever text is still buffered, complete sentence or not.
+    ///
+    /// Call this once the input is finished. The session can keep receiving
+    /// text afterwards.
+    pub fn flush(&mut self) -> Result<Vec<SynthesisChunk>, KokoroError> {
+        self.synthesize_prefix(self.buffer.len())
+    }
+
+    /// Text that has been pushed but not synthesized yet.
+    pub fn pending_text(&self) -> &str {
+        &self.buffer
+    }
+
+    fn synthesize_prefix(&mut self, len: usize) -> Result<Vec<SynthesisChunk>, KokoroError> {
+        let text: String = self.buffer.drain(..len).collect();
+        let base = self.offset;
+        self.offset += len;
+
+        let mut chunks = Vec::new();
+        for sentence in split_sentences_for(&text, self.sentence_lang()) {
+            let sentence_text = &text[sentence.clone()];
+            let (voice, lang) = resolve_voice(
+                Some(&*self.model),
+                self.detect_language,
+                sentence_text,
+                &self.params.voice,
+                self.language,
+            );
+            let p = &self.params;
+            let stream = self
+                .model
+                .synthesize_stream(
+                    sentence_text,
+                    &voice,
+                    p.speed,
+                    p.style_index,
+                    &mut *self.phonemizer,
+                )
+                .with_normalization(p.normalize)
+                .with_max_chunk_chars(p.max_chunk_chars)
+                .with_language(lang);
+            let start = base + sentence.start;
+            for chunk in stream {
+                let mut chunk = chunk?;
+                chunk.text_span = start + chunk.text_span.start..start + chunk.text_span.end;
+                chunks.push(chunk);
+            }
+        }
+        Ok(chunks)
+    }
+
+    /// Language whose rules decide where sentences end.
+    fn sentence_lang(&self) -> &'static str {
+        match self.language {
+            Some(lang) => lang,
+            None => self.model.voice_lang(&self.params.voice),
+        }
+    }
+}
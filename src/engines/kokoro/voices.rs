@@ -1,9 +1,14 @@
 use std::collections::HashMap;
+use std::fmt;
 use std::fs::File;
-use std::io::Read;
+use std::io::{Read, Write};
 use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
 
 use super::model::KokoroError;
+use super::phonemizer::voice_prefix_lang;
 
 /// Storage for all loaded voice style vectors.
 ///
@@ -12,6 +17,7 @@ use super::model::KokoroError;
 /// count, enabling prosody-consistent synthesis.
 pub struct VoiceStore {
     voices: HashMap<String, Vec<[f32; 256]>>,
+    metadata: HashMap<String, VoiceMetadata>,
 }
 
 impl VoiceStore {
@@ -20,52 +26,212 @@ impl VoiceStore {
     /// The file should be a standard .npz archive where each entry is a
     /// .npy file named after the voice (e.g., `af_heart.npy`).
     pub fn load(path: &Path) -> Result<Self, KokoroError> {
-        let file = File::open(path)?;
-        let mut zip = zip::ZipArchive::new(file)
-            .map_err(|e| KokoroError::VoiceParse(format!("Failed to open zip archive: {e}")))?;
-
-        let mut voices = HashMap::new();
-
-        for i in 0..zip.len() {
-            let mut entry = zip.by_index(i).map_err(|e| {
-                KokoroError::VoiceParse(format!("Failed to read zip entry {i}: {e}"))
-            })?;
-
-            let raw_name = entry.name().to_string();
-            // Voice name is the entry name without the .npy extension
-            let voice_name = raw_name
-                .trim_end_matches('/')
-                .trim_end_matches(".npy")
-                .to_string();
-
-            if voice_name.is_empty() || raw_name.ends_with('/') {
-                continue;
-            }
+        let voices = read_npz(path)?;
+        log::info!("Loaded {} voices", voices.len());
+        Ok(Self {
+            voices,
+            metadata: HashMap::new(),
+        })
+    }
+
+    /// Read a voice metadata sidecar file and merge it over the metadata
+    /// already loaded. Returns the number of voices described.
+    ///
+    /// The file is a JSON object keyed by voice name; every field is
+    /// optional and overrides what the name implies:
+    ///
+    /// ```json
+    /// {
+    ///   "af_brand": { "name": "Brand", "language": "en-us", "gender": "female",
+    ///                 "accent": "American", "quality": "B" }
+    /// }
+    /// ```
+    pub fn load_metadata(&mut self, path: &Path) -> Result<usize, KokoroError> {
+        let content = std::fs::read_to_string(path)?;
+        let count = self
+            .parse_metadata(&content)
+            .map_err(|e| KokoroError::VoiceParse(format!("{}: {e}", path.display())))?;
+        log::info!("Loaded metadata for {count} voices from {}", path.display());
+        Ok(count)
+    }
+
+    /// Merge voice metadata from the contents of a sidecar file. See
+    /// [`load_metadata`](Self::load_metadata).
+    pub fn parse_metadata(&mut self, content: &str) -> Result<usize, serde_json::Error> {
+        let entries: HashMap<String, VoiceMetadata> = serde_json::from_str(content)?;
+        let count = entries.len();
+        self.metadata.extend(entries);
+        Ok(count)
+    }
+
+    /// The metadata of a voice, as read from a sidecar file or set with
+    /// [`set_metadata`](Self::set_metadata).
+    pub fn metadata(&self, name: &str) -> Option<&VoiceMetadata> {
+        self.metadata.get(name)
+    }
+
+    /// Set the metadata of a voice, replacing any set before.
+    pub fn set_metadata(&mut self, name: impl Into<String>, metadata: VoiceMetadata) {
+        self.metadata.insert(name.into(), metadata);
+    }
+
+    /// Describe a loaded voice, combining its metadata with what its name
+    /// implies.
+    pub fn info(&self, name: &str) -> Option<VoiceInfo> {
+        let styles = self.voices.get(name)?;
+        Some(VoiceInfo::new(name, self.metadata.get(name), styles.len()))
+    }
+
+    /// Describe every loaded voice, sorted by id.
+    pub fn infos(&self) -> Vec<VoiceInfo> {
+        self.list_voices()
+            .into_iter()
+            .filter_map(|name| self.info(name))
+            .collect()
+    }
 
-            let mut data = Vec::new();
-            entry
-                .read_to_end(&mut data)
-                .map_err(|e| KokoroError::VoiceParse(format!("Failed to read {raw_name}: {e}")))?;
+    /// Add a voice, replacing any voice of the same name. Returns `true` if
+    /// a voice was replaced.
+    ///
+    /// Each entry of `styles` is the style vector for that phoneme count.
+    /// Names cannot be empty or contain `+` or `:`, which mark a
+    /// [`VoiceMix`].
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        styles: Vec<[f32; 256]>,
+    ) -> Result<bool, KokoroError> {
+        let name = name.into();
+        if name.trim().is_empty() || VoiceMix::is_mix(&name) {
+            return Err(KokoroError::VoiceParse(format!(
+                "invalid voice name '{name}'"
+            )));
+        }
+        if styles.is_empty() {
+            return Err(KokoroError::VoiceParse(format!("{name}: no style vectors")));
+        }
+        Ok(self.voices.insert(name, styles).is_some())
+    }
+
+    /// Remove a voice and its metadata. Returns `true` if the voice existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.metadata.remove(name);
+        self.voices.remove(name).is_some()
+    }
+
+    /// Add a voice from a standalone `.npy` file: a little-endian float32
+    /// array of shape `[N, 256]` or `[N, 1, 256]`.
+    pub fn load_npy(&mut self, name: &str, path: &Path) -> Result<bool, KokoroError> {
+        let data = std::fs::read(path)?;
+        self.insert(name, parse_npy(&data, &path.display().to_string())?)
+    }
 
-            let style_vectors = parse_npy(&data, &raw_name)?;
-            voices.insert(voice_name, style_vectors);
+    /// Add a voice from a file of raw little-endian `f32` values, 256 per
+    /// style vector.
+    pub fn load_raw(&mut self, name: &str, path: &Path) -> Result<bool, KokoroError> {
+        let data = std::fs::read(path)?;
+        self.insert(name, parse_styles(&data, &path.display().to_string())?)
+    }
+
+    /// Add every voice of a `.npz` archive (see [`load`](Self::load)),
+    /// replacing voices of the same name. Returns the names added, sorted.
+    pub fn load_npz(&mut self, path: &Path) -> Result<Vec<String>, KokoroError> {
+        let mut names = Vec::new();
+        for (name, styles) in read_npz(path)? {
+            self.insert(name.clone(), styles)?;
+            names.push(name);
+        }
+        names.sort_unstable();
+        Ok(names)
+    }
+
+    /// Write voices to a `.npz` archive that [`load`](Self::load), numpy's
+    /// `np.load` and the Python kokoro-onnx tools can read.
+    ///
+    /// Each voice is stored as `{name}.npy`, a float32 array of shape
+    /// `[N, 1, 256]` like the official voice files. Pass
+    /// `&store.list_voices()` to save every voice; to ship a blend, add it
+    /// under a name first (see [`mix_styles`](Self::mix_styles)).
+    pub fn save(&self, path: &Path, names: &[&str]) -> Result<(), KokoroError> {
+        let mut entries = Vec::with_capacity(names.len());
+        for &name in names {
+            let styles = self
+                .voices
+                .get(name)
+                .ok_or_else(|| KokoroError::VoiceNotFound(name.to_string()))?;
+            entries.push((name, styles));
         }
 
-        log::info!("Loaded {} voices", voices.len());
-        Ok(Self { voices })
+        let mut zip = zip::ZipWriter::new(File::create(path)?);
+        // np.savez stores entries uncompressed.
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        for (name, styles) in entries {
+            zip.start_file(format!("{name}.npy"), options)
+                .map_err(std::io::Error::other)?;
+            zip.write_all(&write_npy(styles))?;
+        }
+        zip.finish().map_err(std::io::Error::other)?;
+        Ok(())
+    }
+
+    /// Style vectors of a voice, one per phoneme count.
+    pub fn styles(&self, name: &str) -> Option<&[[f32; 256]]> {
+        self.voices.get(name).map(Vec::as_slice)
+    }
+
+    /// Style vectors of a blend at every index, as long as its longest
+    /// voice. The result can be added as a voice of its own with
+    /// [`insert`](Self::insert).
+    pub fn mix_styles(&self, mix: &VoiceMix) -> Result<Vec<[f32; 256]>, KokoroError> {
+        let mut len = 0;
+        for (name, _) in &mix.components {
+            let styles = self
+                .voices
+                .get(name)
+                .ok_or_else(|| KokoroError::VoiceNotFound(name.clone()))?;
+            len = len.max(styles.len());
+        }
+        (0..len).map(|idx| self.get_mix_style(mix, idx)).collect()
     }
 
     /// Get the style vector for a voice at the given index.
     ///
-    /// The index is clamped to the valid range, so any index is safe.
+    /// `voice` is a voice name or a [`VoiceMix`] such as
+    /// `"af_heart:0.6+bf_emma:0.4"`. The index is clamped to the valid range
+    /// of each voice, so any index is safe.
     pub fn get_style(&self, voice: &str, idx: usize) -> Result<[f32; 256], KokoroError> {
-        let styles = self
-            .voices
-            .get(voice)
-            .ok_or_else(|| KokoroError::VoiceNotFound(voice.to_string()))?;
+        if let Some(styles) = self.voices.get(voice) {
+            return Ok(style_at(styles, idx));
+        }
+        if !VoiceMix::is_mix(voice) {
+            return Err(KokoroError::VoiceNotFound(voice.to_string()));
+        }
+        self.get_mix_style(&voice.parse()?, idx)
+    }
 
-        let clamped = idx.min(styles.len().saturating_sub(1));
-        Ok(styles[clamped])
+    /// Weighted average of the style vectors of the voices in `mix` at the
+    /// given index.
+    pub fn get_mix_style(&self, mix: &VoiceMix, idx: usize) -> Result<[f32; 256], KokoroError> {
+        let total: f32 = mix.components.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return Err(KokoroError::VoiceMix(format!(
+                "weights of '{mix}' must add up to more than 0"
+            )));
+        }
+
+        let mut blended = [0f32; 256];
+        for (name, weight) in &mix.components {
+            let styles = self
+                .voices
+                .get(name)
+                .ok_or_else(|| KokoroError::VoiceNotFound(name.clone()))?;
+            let style = style_at(styles, idx);
+            for (out, value) in blended.iter_mut().zip(style) {
+                *out += value * weight / total;
+            }
+        }
+        Ok(blended)
     }
 
     /// List all available voice names in sorted order.
@@ -76,9 +242,228 @@ impl VoiceStore {
     }
 }
 
+/// Read every voice of a `.npz` archive.
+fn read_npz(path: &Path) -> Result<HashMap<String, Vec<[f32; 256]>>, KokoroError> {
+    let file = File::open(path)?;
+    let mut zip = zip::ZipArchive::new(file)
+        .map_err(|e| KokoroError::VoiceParse(format!("Failed to open zip archive: {e}")))?;
+
+    let mut voices = HashMap::new();
+
+    for i in 0..zip.len() {
+        let mut entry = zip
+            .by_index(i)
+            .map_err(|e| KokoroError::VoiceParse(format!("Failed to read zip entry {i}: {e}")))?;
+
+        let raw_name = entry.name().to_string();
+        // Voice name is the entry name without the .npy extension
+        let voice_name = raw_name
+            .trim_end_matches('/')
+            .trim_end_matches(".npy")
+            .to_string();
+
+        if voice_name.is_empty() || raw_name.ends_with('/') {
+            continue;
+        }
+
+        let mut data = Vec::new();
+        entry
+            .read_to_end(&mut data)
+            .map_err(|e| KokoroError::VoiceParse(format!("Failed to read {raw_name}: {e}")))?;
+
+        let style_vectors = parse_npy(&data, &raw_name)?;
+        voices.insert(voice_name, style_vectors);
+    }
+
+    Ok(voices)
+}
+
+/// Descriptive fields of a voice, as read from a metadata sidecar file.
+///
+/// Fields left out are derived from the voice name; see [`VoiceInfo`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VoiceMetadata {
+    /// Name to show in a voice picker.
+    pub name: Option<String>,
+    /// espeak-ng language code, e.g. `"en-us"`.
+    pub language: Option<String>,
+    pub gender: Option<VoiceGender>,
+    /// Regional accent, e.g. `"British"`.
+    pub accent: Option<String>,
+    /// Quality grade, e.g. `"A"` or `"C+"`.
+    pub quality: Option<String>,
+}
+
+/// Gender of a voice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VoiceGender {
+    Female,
+    Male,
+}
+
+/// Description of a loaded voice, for voice pickers.
+///
+/// Fields missing from the voice's [`VoiceMetadata`] follow the
+/// `{language}{gender}_{name}` naming convention: `bf_emma` is a British
+/// English (`"en-gb"`) female voice shown as "Emma". Voices whose name does
+/// not follow it have no language or gender unless their metadata sets one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoiceInfo {
+    /// Voice name as passed to synthesis, e.g. `"bf_emma"`.
+    pub id: String,
+    /// Name to show in a voice picker, e.g. `"Emma"`.
+    pub display_name: String,
+    /// espeak-ng language code, e.g. `"en-gb"`.
+    pub language: Option<String>,
+    pub gender: Option<VoiceGender>,
+    /// Regional accent, e.g. `"British"`.
+    pub accent: Option<String>,
+    /// Quality grade, only known from metadata.
+    pub quality: Option<String>,
+    /// Number of style vectors, one per phoneme count.
+    pub style_count: usize,
+}
+
+impl VoiceInfo {
+    fn new(id: &str, metadata: Option<&VoiceMetadata>, style_count: usize) -> Self {
+        let metadata = metadata.cloned().unwrap_or_default();
+        let prefix_lang = voice_prefix_lang(id).filter(|_| id.as_bytes().get(2) == Some(&b'_'));
+        let gender = match id.as_bytes() {
+            [_, b'f', ..] if prefix_lang.is_some() => Some(VoiceGender::Female),
+            [_, b'm', ..] if prefix_lang.is_some() => Some(VoiceGender::Male),
+            _ => None,
+        };
+        let language = metadata.language.or(prefix_lang.map(str::to_string));
+        let accent = metadata.accent.or_else(|| {
+            let accent = match language.as_deref()? {
+                "en-us" => "American",
+                "en-gb" => "British",
+                "pt-br" => "Brazilian",
+                _ => return None,
+            };
+            Some(accent.to_string())
+        });
+        let display_name = metadata.name.unwrap_or_else(|| {
+            let base = match id.split_once('_') {
+                Some((_, base)) if prefix_lang.is_some() => base,
+                _ => id,
+            };
+            let mut chars = base.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        });
+        Self {
+            id: id.to_string(),
+            display_name,
+            language,
+            gender: metadata.gender.or(gender),
+            accent,
+            quality: metadata.quality,
+            style_count,
+        }
+    }
+}
+
+fn style_at(styles: &[[f32; 256]], idx: usize) -> [f32; 256] {
+    styles[idx.min(styles.len().saturating_sub(1))]
+}
+
+/// A weighted blend of voices, written `"af_heart:0.6+bf_emma:0.4"`.
+///
+/// A mix can be given wherever a voice name is accepted. Its style vectors
+/// are the weighted average of the components' vectors at the same index.
+/// Weights are relative (they are scaled to add up to 1) and a component
+/// without a weight counts as 1, so `"af_heart+bf_emma"` is an even blend.
+/// The language of a mix is the language of its first voice.
+///
+/// ```rust
+/// use tts_rs::engines::kokoro::VoiceMix;
+///
+/// let mix = VoiceMix::new().with("af_heart", 0.6).with("bf_emma", 0.4);
+/// assert_eq!(mix.to_string(), "af_heart:0.6+bf_emma:0.4");
+/// assert_eq!("af_heart:0.6+bf_emma:0.4".parse::<VoiceMix>().unwrap(), mix);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VoiceMix {
+    components: Vec<(String, f32)>,
+}
+
+impl VoiceMix {
+    /// An empty mix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `voice` with the given relative weight.
+    pub fn with(mut self, voice: impl Into<String>, weight: f32) -> Self {
+        self.components.push((voice.into(), weight));
+        self
+    }
+
+    /// The voices of the mix with their weights, in order.
+    pub fn components(&self) -> &[(String, f32)] {
+        &self.components
+    }
+
+    /// Whether `voice` is written as a mix rather than a single voice name.
+    pub(crate) fn is_mix(voice: &str) -> bool {
+        voice.contains(['+', ':'])
+    }
+}
+
+impl FromStr for VoiceMix {
+    type Err = KokoroError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let mut mix = Self::new();
+        for component in spec.split('+') {
+            let (name, weight) = match component.split_once(':') {
+                Some((name, weight)) => {
+                    let weight = weight.trim().parse::<f32>().map_err(|_| {
+                        KokoroError::VoiceMix(format!("invalid weight '{weight}' in '{spec}'"))
+                    })?;
+                    (name, weight)
+                }
+                None => (component, 1.0),
+            };
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(KokoroError::VoiceMix(format!(
+                    "missing voice name in '{spec}'"
+                )));
+            }
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(KokoroError::VoiceMix(format!(
+                    "weight of '{name}' must be a non-negative number"
+                )));
+            }
+            mix = mix.with(name, weight);
+        }
+        Ok(mix)
+    }
+}
+
+impl fmt::Display for VoiceMix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, weight)) in self.components.iter().enumerate() {
+            if i > 0 {
+                f.write_str("+")?;
+            }
+            write!(f, "{name}:{weight}")?;
+        }
+        Ok(())
+    }
+}
+
 /// Parse a numpy .npy file into a list of style vectors.
 ///
-/// Expects a 2D float32 array of shape `[N, 256]` in little-endian format.
+/// Expects a little-endian float32 array in C order of shape `[N, 256]` or
+/// `[N, 1, 256]` (the layout of the official voices); other dtypes, orders
+/// and shapes are rejected.
 fn parse_npy(data: &[u8], name: &str) -> Result<Vec<[f32; 256]>, KokoroError> {
     // Verify numpy magic bytes: \x93NUMPY
     if data.len() < 10 {
@@ -94,9 +479,22 @@ fn parse_npy(data: &[u8], name: &str) -> Result<Vec<[f32; 256]>, KokoroError> {
         )));
     }
 
-    // major version at [6], minor at [7], header_len at [8..10] (little-endian u16)
-    let header_len = u16::from_le_bytes([data[8], data[9]]) as usize;
-    let data_offset = 10 + header_len;
+    // major version at [6], minor at [7], then the header length: a
+    // little-endian u16 at [8..10] for version 1, a u32 at [8..12] after.
+    let (header_offset, data_offset) = if data[6] >= 2 {
+        if data.len() < 12 {
+            return Err(KokoroError::VoiceParse(format!(
+                "{name}: file too short ({} bytes)",
+                data.len()
+            )));
+        }
+        (
+            12,
+            12 + u32::from_le_bytes([data[8], data[9], data[10], data[11]]) as usize,
+        )
+    } else {
+        (10, 10 + u16::from_le_bytes([data[8], data[9]]) as usize)
+    };
 
     if data.len() < data_offset {
         return Err(KokoroError::VoiceParse(format!(
@@ -105,7 +503,92 @@ fn parse_npy(data: &[u8], name: &str) -> Result<Vec<[f32; 256]>, KokoroError> {
         )));
     }
 
+    let header = String::from_utf8_lossy(&data[header_offset..data_offset]);
+    let n_styles =
+        parse_npy_header(&header).map_err(|e| KokoroError::VoiceParse(format!("{name}: {e}")))?;
     let float_data = &data[data_offset..];
+    let expected = n_styles.checked_mul(256 * 4).ok_or_else(|| {
+        KokoroError::VoiceParse(format!("{name}: {n_styles} style vectors is too many"))
+    })?;
+    if float_data.len() != expected {
+        return Err(KokoroError::VoiceParse(format!(
+            "{name}: expected {expected} bytes of data for {n_styles} style vectors, got {}",
+            float_data.len()
+        )));
+    }
+
+    parse_styles(float_data, name)
+}
+
+/// Check that an `.npy` header describes little-endian `f32` style vectors
+/// in C order, of shape `[N, 256]` or `[N, 1, 256]`, and return `N`.
+fn parse_npy_header(header: &str) -> Result<usize, String> {
+    let descr = npy_header_value(header, "descr").ok_or("header has no 'descr'")?;
+    if descr.trim_matches(['\'', '"']) != "<f4" {
+        return Err(format!(
+            "unsupported dtype {descr}, expected little-endian float32 ('<f4')"
+        ));
+    }
+    let fortran_order =
+        npy_header_value(header, "fortran_order").ok_or("header has no 'fortran_order'")?;
+    if fortran_order != "False" {
+        return Err("Fortran-order arrays are not supported".to_string());
+    }
+    let shape = npy_header_value(header, "shape").ok_or("header has no 'shape'")?;
+    let dims = shape
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| format!("invalid shape {shape}"))?;
+    match dims[..] {
+        [n, 256] | [n, 1, 256] => Ok(n),
+        _ => Err(format!("shape {shape} is not [N, 256] or [N, 1, 256]")),
+    }
+}
+
+/// The raw value of `key` in an `.npy` header dict, e.g. `'<f4'` for
+/// `descr` or `(510, 1, 256)` for `shape`.
+fn npy_header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = [format!("'{key}'"), format!("\"{key}\"")]
+        .iter()
+        .find_map(|quoted| header.find(quoted.as_str()).map(|at| at + quoted.len()))?;
+    let value = header[start..].trim_start().strip_prefix(':')?.trim_start();
+    let end = match value.chars().next()? {
+        '(' => value.find(')')? + 1,
+        quote @ ('\'' | '"') => value[1..].find(quote)? + 2,
+        _ => value.find([',', '}']).unwrap_or(value.len()),
+    };
+    Some(value[..end].trim())
+}
+
+/// Encode style vectors as a version 1.0 `.npy` file of shape `[N, 1, 256]`.
+fn write_npy(styles: &[[f32; 256]]) -> Vec<u8> {
+    let mut header = format!(
+        "{{'descr': '<f4', 'fortran_order': False, 'shape': ({}, 1, 256), }}",
+        styles.len()
+    );
+    // numpy pads the header with spaces so the data starts 64-byte aligned,
+    // ending it with a newline.
+    let unpadded = 10 + header.len() + 1;
+    header.push_str(&" ".repeat(unpadded.next_multiple_of(64) - unpadded));
+    header.push('\n');
+
+    let mut npy = Vec::with_capacity(10 + header.len() + styles.len() * 256 * 4);
+    npy.extend_from_slice(b"\x93NUMPY\x01\x00");
+    npy.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    npy.extend_from_slice(header.as_bytes());
+    for value in styles.iter().flatten() {
+        npy.extend_from_slice(&value.to_le_bytes());
+    }
+    npy
+}
+
+/// Parse little-endian `f32` values into style vectors of 256 floats each.
+fn parse_styles(float_data: &[u8], name: &str) -> Result<Vec<[f32; 256]>, KokoroError> {
     if !float_data.len().is_multiple_of(4) {
         return Err(KokoroError::VoiceParse(format!(
             "{name}: float data length {} is not a multiple of 4",
@@ -139,3 +622,166 @@ fn parse_npy(data: &[u8], name: &str) -> Result<Vec<[f32; 256]>, KokoroError> {
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{parse_npy, write_npy, VoiceGender, VoiceMix, VoiceStore};
+    use crate::engines::kokoro::KokoroError;
+
+    #[test]
+    fn writes_aligned_npy_and_npz_archives() {
+        let styles = vec![[0.25; 256], [0.5; 256]];
+        let npy = write_npy(&styles);
+        let header_len = u16::from_le_bytes([npy[8], npy[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        let header = std::str::from_utf8(&npy[10..10 + header_len]).unwrap();
+        assert!(header.contains("'shape': (2, 1, 256)"));
+        assert!(header.ends_with('\n'));
+        assert_eq!(parse_npy(&npy, "v.npy").unwrap(), styles);
+
+        let mut store = VoiceStore {
+            voices: HashMap::from([
+                ("af_heart".to_string(), vec![[1.0; 256], [2.0; 256]]),
+                ("bf_emma".to_string(), vec![[3.0; 256]]),
+            ]),
+            metadata: HashMap::new(),
+        };
+        let blend = store
+            .mix_styles(&"af_heart+bf_emma".parse().unwrap())
+            .unwrap();
+        assert_eq!(blend, vec![[2.0; 256], [2.5; 256]]);
+        store.insert("af_blend", blend.clone()).unwrap();
+
+        let path = std::env::temp_dir().join(format!("tts-rs-voices-{}.npz", std::process::id()));
+        store.save(&path, &["af_blend", "bf_emma"]).unwrap();
+        let loaded = VoiceStore::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.list_voices(), ["af_blend", "bf_emma"]);
+        assert_eq!(loaded.styles("af_blend").unwrap(), blend.as_slice());
+    }
+
+    #[test]
+    fn describes_voices_from_names_and_metadata() {
+        let mut store = VoiceStore {
+            voices: HashMap::from([
+                ("bf_emma".to_string(), vec![[0.0; 256]; 510]),
+                ("brand".to_string(), vec![[0.0; 256]; 3]),
+            ]),
+            metadata: HashMap::new(),
+        };
+        let emma = store.info("bf_emma").unwrap();
+        assert_eq!(emma.display_name, "Emma");
+        assert_eq!(emma.language.as_deref(), Some("en-gb"));
+        assert_eq!(emma.gender, Some(VoiceGender::Female));
+        assert_eq!(emma.accent.as_deref(), Some("British"));
+        assert_eq!((emma.quality, emma.style_count), (None, 510));
+
+        let brand = store.info("brand").unwrap();
+        assert_eq!(brand.display_name, "Brand");
+        assert_eq!((brand.language, brand.gender), (None, None));
+
+        let sidecar = r#"{
+            "bf_emma": { "quality": "B-" },
+            "brand": { "name": "Our Brand", "language": "en-us", "gender": "male" }
+        }"#;
+        assert_eq!(store.parse_metadata(sidecar).unwrap(), 2);
+        let infos = store.infos();
+        assert_eq!(infos[0].quality.as_deref(), Some("B-"));
+        assert_eq!(infos[1].display_name, "Our Brand");
+        assert_eq!(infos[1].language.as_deref(), Some("en-us"));
+        assert_eq!(infos[1].gender, Some(VoiceGender::Male));
+        assert_eq!(infos[1].accent.as_deref(), Some("American"));
+        assert!(store
+            .parse_metadata(r#"{"x": {"gender": "robot"}}"#)
+            .is_err());
+    }
+
+    #[test]
+    fn adds_and_removes_voices() {
+        let mut store = VoiceStore {
+            voices: HashMap::new(),
+            metadata: HashMap::new(),
+        };
+        assert!(!store.insert("my_voice", vec![[0.5; 256]]).unwrap());
+        assert!(store.insert("my_voice", vec![[1.0; 256]]).unwrap());
+        assert_eq!(store.get_style("my_voice", 3).unwrap(), [1.0; 256]);
+        assert!(store.insert("a+b", vec![[1.0; 256]]).is_err());
+        assert!(store.insert("empty", Vec::new()).is_err());
+        store
+            .parse_metadata(r#"{"my_voice": {"name": "Mine"}}"#)
+            .unwrap();
+        assert!(store.remove("my_voice"));
+        assert!(!store.remove("my_voice"));
+        assert!(store.metadata("my_voice").is_none());
+
+        // Version 2 .npy headers store their length in four bytes.
+        let header = b"{'descr': '<f4', 'fortran_order': False, 'shape': (1, 256), }\n";
+        let mut npy = b"\x93NUMPY\x02\x00".to_vec();
+        npy.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        npy.extend_from_slice(header);
+        npy.extend((0..256).flat_map(|i| (i as f32).to_le_bytes()));
+        let styles = parse_npy(&npy, "v2.npy").unwrap();
+        assert_eq!(styles.len(), 1);
+        assert_eq!(styles[0][255], 255.0);
+
+        // numpy's default float64 has a plausible length but is not float32.
+        let header = b"{'descr': '<f8', 'fortran_order': False, 'shape': (2, 256), }\n";
+        let mut npy = b"\x93NUMPY\x01\x00".to_vec();
+        npy.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        npy.extend_from_slice(header);
+        npy.extend((0..512).flat_map(|i| f64::from(i).to_le_bytes()));
+        assert!(matches!(
+            parse_npy(&npy, "f8.npy"),
+            Err(KokoroError::VoiceParse(e)) if e.contains("'<f8'")
+        ));
+        let mut fortran = write_npy(&[[0.0; 256]]);
+        let at = fortran.windows(5).position(|w| w == b"False").unwrap();
+        fortran[at..at + 5].copy_from_slice(b"True ");
+        assert!(parse_npy(&fortran, "f.npy").is_err());
+        let mut truncated = write_npy(&[[0.0; 256], [1.0; 256]]);
+        truncated.truncate(truncated.len() - 1024);
+        assert!(parse_npy(&truncated, "short.npy").is_err());
+        let header = format!(
+            "{{'descr': '<f4', 'fortran_order': False, 'shape': ({}, 256), }}\n",
+            usize::MAX / 512
+        );
+        let mut huge = b"\x93NUMPY\x01\x00".to_vec();
+        huge.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        huge.extend_from_slice(header.as_bytes());
+        assert!(matches!(
+            parse_npy(&huge, "huge.npy"),
+            Err(KokoroError::VoiceParse(e)) if e.contains("too many")
+        ));
+    }
+
+    #[test]
+    fn blends_style_vectors_by_weight() {
+        let store = VoiceStore {
+            voices: HashMap::from([
+                ("af_heart".to_string(), vec![[1.0; 256], [2.0; 256]]),
+                ("bf_emma".to_string(), vec![[3.0; 256]]),
+            ]),
+            metadata: HashMap::new(),
+        };
+
+        let style = store.get_style("af_heart:3+bf_emma:1", 1).unwrap();
+        assert_eq!(style, [2.25; 256]);
+        let style = store.get_style("af_heart+bf_emma", 0).unwrap();
+        assert_eq!(style, [2.0; 256]);
+
+        assert!(matches!(
+            store.get_style("af_heart:0.5+af_nope:0.5", 0),
+            Err(KokoroError::VoiceNotFound(name)) if name == "af_nope"
+        ));
+        assert!(matches!(
+            "af_heart:x".parse::<VoiceMix>(),
+            Err(KokoroError::VoiceMix(_))
+        ));
+        assert!(matches!(
+            store.get_style("af_heart:0+bf_emma:0", 0),
+            Err(KokoroError::VoiceMix(_))
+        ));
+    }
+}
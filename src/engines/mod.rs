@@ -6,6 +6,7 @@
 //!
 //! Enable engines via Cargo features:
 //! - `kokoro` - Kokoro TTS (ONNX format, espeak-ng required)
+//! - `tokio` - Async wrapper around the Kokoro engine (implies `kokoro`)
 
 #[cfg(feature = "kokoro")]
 pub mod kokoro;
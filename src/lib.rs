@@ -1,4 +1,4 @@
-//! # transcribe-rs
+//! # tts-rs
 //!
 //! A Rust library providing text-to-speech synthesis using the Kokoro engine.
 //!
@@ -7,17 +7,18 @@
 //! - **Kokoro TTS**: High-quality text-to-speech with multiple voices and languages
 //! - **Flexible Model Loading**: Load models with custom parameters
 //! - **Multiple Voices**: Support for 9 languages with various voice styles
+//! - **Captions**: SRT and WebVTT export timed from word timestamps
 //!
 //! ## Quick Start
 //!
 //! ```toml
 //! [dependencies]
-//! transcribe-rs = { version = "0.2", features = ["kokoro"] }
+//! tts-rs = { version = "0.2", features = ["kokoro"] }
 //! ```
 //!
 //! ```ignore
 //! use std::path::PathBuf;
-//! use transcribe_rs::{engines::kokoro::KokoroEngine, SynthesisEngine};
+//! use tts_rs::{engines::kokoro::KokoroEngine, SynthesisEngine};
 //!
 //! let mut engine = KokoroEngine::new();
 //! engine.load_model(&PathBuf::from("models/kokoro-v1.0"))?;
@@ -27,22 +28,81 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+pub mod captions;
 pub mod engines;
+mod segment;
 
+use std::ops::Range;
 use std::path::Path;
 
 /// The result of a synthesis (text-to-speech) operation.
 ///
-/// Contains raw f32 audio samples and the sample rate of the output audio.
+/// Contains raw f32 audio samples, the sample rate of the output audio and,
+/// when the engine can provide them, word- and phoneme-level timestamps.
+/// New fields may be added; engines outside this crate build it with
+/// [`SynthesisResult::new`].
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct SynthesisResult {
     /// Raw audio samples as f32 values
     pub samples: Vec<f32>,
     /// Sample rate of the audio (24000 for Kokoro)
     pub sample_rate: u32,
+    /// Timing of each input word, in text order (empty if unsupported)
+    pub words: Vec<WordTimestamp>,
+    /// Timing of each phoneme token, in audio order (empty if unsupported)
+    pub phonemes: Vec<PhonemeTimestamp>,
+    /// Positions of named markers (e.g. SSML `<mark>`), in order
+    pub marks: Vec<MarkTimestamp>,
+    /// espeak-ng code of the language the text was read in, if known. Used
+    /// to tell abbreviations from sentence ends in captions.
+    pub language: Option<String>,
+}
+
+/// When a word of the input text is spoken in the synthesized audio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordTimestamp {
+    /// The word as it appears in the input, including attached punctuation
+    pub text: String,
+    /// Byte range of the word in the input text
+    pub text_span: Range<usize>,
+    /// Phonemes (IPA) the word was pronounced with
+    pub phonemes: String,
+    /// Start of the word in seconds from the beginning of the audio
+    pub start_secs: f64,
+    /// End of the word in seconds from the beginning of the audio
+    pub end_secs: f64,
+}
+
+/// When a phoneme token is spoken in the synthesized audio.
+///
+/// Follows the model's predicted durations when the engine exposes them and
+/// an even split of each chunk otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhonemeTimestamp {
+    /// The phoneme, punctuation mark or word separator the token stands for
+    pub phoneme: char,
+    /// Token id in the model vocabulary
+    pub token_id: i64,
+    /// Start of the phoneme in seconds from the beginning of the audio
+    pub start_secs: f64,
+    /// End of the phoneme in seconds from the beginning of the audio
+    pub end_secs: f64,
 }
 
 impl SynthesisResult {
+    /// Audio without any timestamps, marks or language.
+    pub fn new(samples: Vec<f32>, sample_rate: u32) -> Self {
+        Self {
+            samples,
+            sample_rate,
+            words: Vec::new(),
+            phonemes: Vec::new(),
+            marks: Vec::new(),
+            language: None,
+        }
+    }
+
     /// Write the audio to a 32-bit float WAV file.
     pub fn write_wav(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
         let spec = hound::WavSpec {
@@ -65,6 +125,43 @@ impl SynthesisResult {
     }
 }
 
+/// When a named marker in the input is reached in the synthesized audio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkTimestamp {
+    /// Marker name
+    pub name: String,
+    /// Position in seconds from the beginning of the audio
+    pub time_secs: f64,
+}
+
+/// A piece of audio produced by a streaming synthesis call.
+///
+/// Chunks are yielded in order; concatenating their samples gives the full
+/// utterance.
+#[derive(Debug, Clone)]
+pub struct SynthesisChunk {
+    /// Raw audio samples as f32 values
+    pub samples: Vec<f32>,
+    /// Sample rate of the audio (24000 for Kokoro)
+    pub sample_rate: u32,
+    /// Byte range of the input text this chunk was synthesized from
+    pub text_span: Range<usize>,
+    /// Timing of each phoneme token, in seconds from the start of the chunk
+    /// (empty if unsupported)
+    pub phonemes: Vec<PhonemeTimestamp>,
+}
+
+impl SynthesisChunk {
+    /// Duration of the chunk in seconds.
+    pub fn duration_secs(&self) -> f64 {
+        self.samples.len() as f64 / self.sample_rate as f64
+    }
+}
+
+/// Iterator over audio chunks returned by [`SynthesisEngine::synthesize_stream`].
+pub type SynthesisStream<'a> =
+    Box<dyn Iterator<Item = Result<SynthesisChunk, Box<dyn std::error::Error>>> + 'a>;
+
 /// Common interface for text-to-speech synthesis engines.
 ///
 /// This trait defines the standard operations that all synthesis engines must support.
@@ -97,6 +194,27 @@ pub trait SynthesisEngine {
         params: Option<Self::SynthesisParams>,
     ) -> Result<SynthesisResult, Box<dyn std::error::Error>>;
 
+    /// Synthesize speech incrementally, yielding audio as each chunk is produced.
+    ///
+    /// Lets callers start playback before the whole text has been synthesized.
+    /// The default implementation synthesizes everything up front and yields a
+    /// single chunk covering the whole input; engines that can do better
+    /// override it.
+    fn synthesize_stream<'a>(
+        &'a mut self,
+        text: &'a str,
+        params: Option<Self::SynthesisParams>,
+    ) -> Result<SynthesisStream<'a>, Box<dyn std::error::Error>> {
+        let result = self.synthesize(text, params)?;
+        let chunk = SynthesisChunk {
+            samples: result.samples,
+            sample_rate: result.sample_rate,
+            text_span: 0..text.len(),
+            phonemes: result.phonemes,
+        };
+        Ok(Box::new(std::iter::once(Ok(chunk))))
+    }
+
     /// Synthesize speech from the given text and write to a WAV file.
     ///
     /// Default implementation calls `synthesize()` then `SynthesisResult::write_wav()`.
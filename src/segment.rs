@@ -0,0 +1,174 @@
+//! Telling sentence-final periods from abbreviations and initials.
+
+/// Words that precede a name and so never end a sentence unless the text
+/// ends there ("Dr. Smith", "St. Louis").
+fn titles(lang: &str) -> &'static [&'static str] {
+    match base_lang(lang) {
+        "es" => &[
+            "sr", "sra", "srta", "sres", "dr", "dra", "lic", "ing", "prof", "profa", "arq", "dña",
+            "sto", "sta", "gral", "cnel", "cap",
+        ],
+        "fr" => &[
+            "m", "mm", "mme", "mmes", "mlle", "mlles", "dr", "pr", "me", "mgr", "st", "ste",
+        ],
+        "it" => &[
+            "sig", "sigg", "sig.ra", "sig.na", "dott", "dott.ssa", "prof", "prof.ssa", "ing",
+            "avv", "arch", "geom", "rag", "on", "mons", "gen", "s",
+        ],
+        "pt" => &[
+            "sr", "sra", "srta", "dr", "dra", "prof", "profa", "eng", "exmo", "exma", "ilmo",
+            "ilma", "sto", "sta", "gen",
+        ],
+        "hi" => &[
+            "डॉ",
+            "प्रो",
+            "श्री",
+            "श्रीमती",
+            "dr",
+            "mr",
+            "mrs",
+            "ms",
+            "prof",
+        ],
+        _ => &[
+            "mr", "mrs", "ms", "dr", "prof", "st", "mt", "ft", "sr", "jr", "gen", "gov", "sen",
+            "rep", "capt", "lt", "col", "sgt", "cmdr", "adm", "rev", "hon", "pres", "messrs",
+        ],
+    }
+}
+
+/// Abbreviations that end a sentence only when the next word is capitalized
+/// ("etc. and so on" vs "etc. Then").
+fn abbreviations(lang: &str) -> &'static [&'static str] {
+    match base_lang(lang) {
+        "es" => &[
+            "etc", "pág", "págs", "núm", "tel", "aprox", "ej", "av", "avda", "art", "vol", "cap",
+            "fig", "ud", "uds", "vd", "vds", "admón", "dpto", "ca",
+        ],
+        "fr" => &[
+            "etc", "av", "bd", "cf", "env", "ex", "n", "no", "vol", "chap", "art", "fig", "tél",
+            "p", "pp", "min", "max", "boul",
+        ],
+        "it" => &[
+            "ecc", "pag", "pagg", "es", "cap", "art", "vol", "tel", "fig", "n", "nr", "ca", "cfr",
+            "ad", "sec",
+        ],
+        "pt" => &[
+            "etc", "pág", "págs", "núm", "tel", "aprox", "ex", "av", "art", "cap", "vol", "fig",
+            "n", "ca", "obs",
+        ],
+        "hi" => &["etc", "vs", "no"],
+        _ => &[
+            "etc", "vs", "no", "nos", "fig", "figs", "vol", "vols", "ch", "p", "pp", "ca", "cf",
+            "al", "approx", "est", "dept", "inc", "ltd", "co", "corp", "jan", "feb", "mar", "apr",
+            "jun", "jul", "aug", "sep", "sept", "oct", "nov", "dec", "min", "max", "avg", "ave",
+            "blvd", "rd",
+        ],
+    }
+}
+
+/// The language of an espeak-ng code without its region (`"en-us"` -> `"en"`).
+pub(crate) fn base_lang(lang: &str) -> &str {
+    lang.split(['-', '_']).next().unwrap_or(lang)
+}
+
+/// Whether the `.` at byte `idx` of `text` belongs to an abbreviation, an
+/// initial or a dotted word (`"Node.js"`) rather than ending a sentence.
+///
+/// Abbreviations are looked up in per-language lists for `lang`; languages
+/// without a list use the English one. Initials are single capital letters
+/// (`"J. K. Rowling"`) and dotted letter sequences (`"U.S."`, `"e.g."`). A
+/// period followed by a line break or the end of the text always ends a
+/// sentence.
+pub(crate) fn is_abbreviation_period(text: &str, idx: usize, lang: &str) -> bool {
+    let after = &text[idx + 1..];
+    if after.starts_with(char::is_alphanumeric) {
+        return true;
+    }
+    let following = after.trim_start_matches([' ', '\t', '\u{a0}']);
+    let Some(next) = following.chars().next() else {
+        return false;
+    };
+    if matches!(next, '\n' | '\r') {
+        return false;
+    }
+
+    let word = word_before(text, idx);
+    if word.is_empty() {
+        return false;
+    }
+    if is_initials(word) {
+        return true;
+    }
+    let lower = word.to_lowercase();
+    if titles(lang).contains(&lower.as_str()) {
+        return true;
+    }
+    abbreviations(lang).contains(&lower.as_str()) && !next.is_uppercase()
+}
+
+/// The word directly before byte `idx`, without leading punctuation.
+fn word_before(text: &str, idx: usize) -> &str {
+    let before = &text[..idx];
+    let start = before
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| c.is_whitespace())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    before[start..].trim_start_matches(|c: char| !c.is_alphanumeric())
+}
+
+/// A single capital letter (`"J"`, but not the pronoun `"I"`) or letters
+/// separated by periods (`"U.S"`, `"e.g"`, `"a.m"`).
+fn is_initials(word: &str) -> bool {
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => c.is_uppercase() && c != 'I',
+        _ => {
+            word.contains('.')
+                && word.split('.').all(|letter| {
+                    letter.chars().count() == 1 && letter.chars().all(char::is_alphabetic)
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_abbreviation_period;
+
+    fn periods(text: &str, lang: &str) -> Vec<bool> {
+        text.match_indices('.')
+            .map(|(idx, _)| is_abbreviation_period(text, idx, lang))
+            .collect()
+    }
+
+    #[test]
+    fn detects_abbreviations_and_initials() {
+        assert_eq!(
+            periods("Dr. Smith lives in St. Louis.", "en-us"),
+            [true, true, false]
+        );
+        assert_eq!(
+            periods("Use tools, e.g. this one.", "en-gb"),
+            [true, true, false]
+        );
+        assert_eq!(
+            periods("The U.S. Army. J. K. Rowling. So did I. Yes.", "en-us"),
+            [true, true, false, true, true, false, false, false]
+        );
+        assert_eq!(
+            periods("Cats, dogs, etc. Then birds, etc. and more.", "en-us"),
+            [false, true, false]
+        );
+        assert_eq!(
+            periods("See node.js and No. 5.\nNext.", "en-us"),
+            [true, true, false, false]
+        );
+        assert_eq!(
+            periods("La Sra. García llegó. Fin.", "es"),
+            [true, false, false]
+        );
+        assert_eq!(periods("Il Dott. Rossi. Fine.", "it"), [true, false, false]);
+    }
+}